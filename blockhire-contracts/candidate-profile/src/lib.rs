@@ -1,5 +1,30 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, BytesN};
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, Address, Env, String, Symbol, Vec, BytesN, symbol_short};
+
+// Upper bound on items returned by a single paginated query
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Event topics (listed in the README)
+const PROFILE: Symbol = symbol_short!("profile");      // Profile lifecycle
+const DOCUMENT: Symbol = symbol_short!("document");    // Uploads, versions, verification
+const CONFIG: Symbol = symbol_short!("config");        // Admin wiring
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    ProfileNotFound = 3,
+    DocumentNotFound = 4,
+    DocumentAlreadyExists = 5,
+    DocumentRetired = 6,
+    DocumentSuperseded = 7,
+    VerifierNotTrusted = 8,
+    SelfVerification = 9,
+    VerificationNotFound = 10,
+    InvalidPolicy = 11,
+}
 
 // Candidate profile stored on-chain
 #[contracttype]
@@ -10,6 +35,7 @@ pub struct CandidateProfile {
     pub skills_hash: BytesN<32>,     // Hash of skills for integrity
     pub is_verified: bool,           // Profile verification status
     pub verification_count: u32,     // Number of verified documents
+    pub verifier_count: u32,         // Number of distinct verifiers
     pub created_at: u64,            // Timestamp
     pub updated_at: u64,
 }
@@ -24,6 +50,71 @@ pub struct Document {
     pub is_verified: bool,
     pub verified_by: Option<Address>, // Employer who verified
     pub verified_at: Option<u64>,
+    pub version: u32,                 // 1 for the original upload
+    pub root_hash: BytesN<32>,        // Hash of the original upload of this document
+    pub is_superseded: bool,          // A newer version exists; see get_superseded_by
+    pub is_retired: bool,             // Withdrawn by the candidate
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RevocationReason {
+    IssuedInError,
+    Expired,
+    Fraudulent,
+    Outdated,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TombstoneKind {
+    DocumentRetired,
+    VerificationRevoked,
+}
+
+// Permanent record of a retirement or revoked verification
+#[contracttype]
+#[derive(Clone)]
+pub struct Tombstone {
+    pub kind: TombstoneKind,
+    pub actor: Address,             // Candidate or verifier who withdrew
+    pub reason: RevocationReason,
+    pub timestamp: u64,
+}
+
+// Admin-configured rule for when a profile counts as verified
+#[contracttype]
+#[derive(Clone)]
+pub struct VerificationPolicy {
+    pub min_verifiers: u32,            // Distinct verifiers across all documents
+    pub min_documents: u32,            // Distinct verified documents
+    pub required_doc_types: Vec<String>, // e.g. ["degree", "id"]
+}
+
+// Mirror of job-application's Reputation; field names must match to decode
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reputation {
+    pub count: u32,
+    pub total_score: u64,
+    pub average: u32,                  // Hundredths, 450 = 4.50
+    pub recent: Vec<u32>,
+    pub recent_average: u32,
+    pub updated_at: u64,
+}
+
+// Interface of the deployed verifier-registry contract
+#[contractclient(name = "VerifierRegistryClient")]
+pub trait VerifierRegistry {
+    fn is_active_verifier(env: Env, verifier: Address) -> bool;
+}
+
+// Interface of the deployed job-application contract
+#[contractclient(name = "JobApplicationClient")]
+pub trait JobApplication {
+    fn get_reputation(env: Env, address: Address) -> Reputation;
 }
 
 #[contract]
@@ -38,7 +129,7 @@ impl CandidateProfileContract {
         candidate: Address,
         ipfs_cid: String,
         skills_hash: BytesN<32>,
-    ) -> CandidateProfile {
+    ) -> Result<CandidateProfile, Error> {
         candidate.require_auth();
         
         let timestamp = env.ledger().timestamp();
@@ -47,6 +138,7 @@ impl CandidateProfileContract {
         // Check if profile exists
         let existing: Option<CandidateProfile> = env.storage().persistent().get(&profile_key);
         
+        let action = if existing.is_some() { symbol_short!("update") } else { symbol_short!("register") };
         let profile = match existing {
             Some(mut p) => {
                 // Update existing profile
@@ -56,21 +148,25 @@ impl CandidateProfileContract {
                 p
             },
             None => {
-                // Create new profile
-                CandidateProfile {
+                // Create new profile, picking up any verifications made before registration
+                let mut p = CandidateProfile {
                     wallet: candidate.clone(),
                     ipfs_cid,
                     skills_hash,
                     is_verified: false,
                     verification_count: 0,
+                    verifier_count: 0,
                     created_at: timestamp,
                     updated_at: timestamp,
-                }
+                };
+                Self::evaluate_profile(&env, &mut p);
+                p
             }
         };
         
         env.storage().persistent().set(&profile_key, &profile);
-        profile
+        env.events().publish((PROFILE, action, candidate), profile.clone());
+        Ok(profile)
     }
     
     /// Add a document to candidate's profile
@@ -80,21 +176,136 @@ impl CandidateProfileContract {
         doc_hash: BytesN<32>,
         doc_type: String,
         ipfs_cid: String,
-    ) -> Document {
+    ) -> Result<Document, Error> {
         candidate.require_auth();
         
-        let document = Document {
-            doc_hash: doc_hash.clone(),
-            doc_type,
+        let document = Self::store_new_document(&env, &candidate, doc_hash.clone(), doc_type, ipfs_cid, 1, doc_hash)?;
+        env.events().publish((DOCUMENT, symbol_short!("add"), candidate), document.clone());
+        Ok(document)
+    }
+    
+    /// Upload a new version of an existing document; the previous version is kept as-is
+    pub fn add_document_version(
+        env: Env,
+        candidate: Address,
+        previous_hash: BytesN<32>,
+        doc_hash: BytesN<32>,
+        ipfs_cid: String,
+    ) -> Result<Document, Error> {
+        candidate.require_auth();
+        
+        let previous_key = (candidate.clone(), previous_hash.clone());
+        let mut previous: Document = env.storage().persistent()
+            .get(&previous_key)
+            .ok_or(Error::DocumentNotFound)?;
+        if previous.is_retired {
+            return Err(Error::DocumentRetired);
+        }
+        if previous.is_superseded {
+            return Err(Error::DocumentSuperseded);
+        }
+        
+        let document = Self::store_new_document(
+            &env,
+            &candidate,
+            doc_hash,
+            previous.doc_type.clone(),
             ipfs_cid,
-            is_verified: false,
-            verified_by: None,
-            verified_at: None,
+            previous.version + 1,
+            previous.root_hash.clone(),
+        )?;
+        
+        previous.is_superseded = true;
+        env.storage().persistent().set(&previous_key, &previous);
+        
+        // Explicit links between neighbouring versions, kept beside the documents
+        // (the SDK cannot convert Option<BytesN<32>> struct fields)
+        let next_key = (symbol_short!("next_ver"), candidate.clone(), previous_hash.clone());
+        let prev_key = (symbol_short!("prev_ver"), candidate.clone(), document.doc_hash.clone());
+        env.storage().persistent().set(&next_key, &document.doc_hash);
+        env.storage().persistent().set(&prev_key, &previous_hash);
+        env.events().publish((DOCUMENT, symbol_short!("add"), candidate.clone()), document.clone());
+        
+        // Only the latest version of a document counts towards the profile
+        Self::sync_profile(&env, &candidate);
+        
+        Ok(document)
+    }
+    
+    /// Get every version of a document, oldest first
+    pub fn get_document_history(
+        env: Env,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+    ) -> Vec<Document> {
+        let mut history = Vec::new(&env);
+        let root_hash = match Self::get_document(env.clone(), candidate.clone(), doc_hash) {
+            Some(document) => document.root_hash,
+            None => return history,
         };
         
-        let doc_key = (candidate, doc_hash);
-        env.storage().persistent().set(&doc_key, &document);
-        document
+        let versions: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&(symbol_short!("versions"), candidate.clone(), root_hash))
+            .unwrap_or(Vec::new(&env));
+        for version_hash in versions.iter() {
+            if let Some(document) = Self::get_document(env.clone(), candidate.clone(), version_hash) {
+                history.push_back(document);
+            }
+        }
+        history
+    }
+    
+    /// Get the version a document replaced; None for an original upload
+    pub fn get_supersedes(env: Env, candidate: Address, doc_hash: BytesN<32>) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(symbol_short!("prev_ver"), candidate, doc_hash))
+    }
+    
+    /// Get the version that replaced a document; None for the latest version
+    pub fn get_superseded_by(env: Env, candidate: Address, doc_hash: BytesN<32>) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(symbol_short!("next_ver"), candidate, doc_hash))
+    }
+    
+    /// Get the latest version of a document
+    pub fn get_latest_version(
+        env: Env,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+    ) -> Option<Document> {
+        Self::get_document_history(env, candidate, doc_hash).last()
+    }
+    
+    /// One-time setup of the contract admin and the verifier registry to consult
+    pub fn initialize(env: Env, admin: Address, verifier_registry: Address) -> Result<(), Error> {
+        if env.storage().persistent().has(&symbol_short!("admin")) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().persistent().set(&symbol_short!("admin"), &admin);
+        env.storage().persistent().set(&symbol_short!("registry"), &verifier_registry);
+        env.events().publish((CONFIG, symbol_short!("init")), (admin, verifier_registry));
+        Ok(())
+    }
+    
+    /// Admin points the contract at a different verifier registry
+    pub fn set_verifier_registry(env: Env, verifier_registry: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().persistent().set(&symbol_short!("registry"), &verifier_registry);
+        env.events().publish((CONFIG, symbol_short!("registry")), verifier_registry);
+        Ok(())
+    }
+    
+    /// Admin sets the job-application contract that holds post-hire ratings
+    pub fn set_job_application(env: Env, job_application: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().persistent().set(&symbol_short!("jobs"), &job_application);
+        env.events().publish((CONFIG, symbol_short!("jobs")), job_application);
+        Ok(())
+    }
+    
+    /// Get a candidate's post-hire reputation from job-application; None until it is configured
+    pub fn get_reputation(env: Env, candidate: Address) -> Option<Reputation> {
+        let job_application: Address = env.storage().persistent().get(&symbol_short!("jobs"))?;
+        Some(JobApplicationClient::new(&env, &job_application).get_reputation(&candidate))
     }
     
     /// Employer verifies a candidate's document
@@ -103,32 +314,162 @@ impl CandidateProfileContract {
         employer: Address,
         candidate: Address,
         doc_hash: BytesN<32>,
-    ) -> Document {
+    ) -> Result<Document, Error> {
         employer.require_auth();
+        Self::require_trusted_verifier(&env, &employer, &candidate)?;
         
-        let doc_key = (candidate.clone(), doc_hash);
+        let doc_key = (candidate.clone(), doc_hash.clone());
         let mut document: Document = env.storage().persistent()
             .get(&doc_key)
-            .expect("Document not found");
+            .ok_or(Error::DocumentNotFound)?;
+        if document.is_retired {
+            return Err(Error::DocumentRetired);
+        }
+        
+        // Repeat verifications by the same verifier are no-ops
+        let verifiers_key = (symbol_short!("doc_vrfs"), candidate.clone(), doc_hash);
+        let mut verifiers: Vec<Address> = env.storage().persistent()
+            .get(&verifiers_key)
+            .unwrap_or(Vec::new(&env));
+        if verifiers.contains(&employer) {
+            return Ok(document);
+        }
+        verifiers.push_back(employer.clone());
+        env.storage().persistent().set(&verifiers_key, &verifiers);
+        env.events().publish((DOCUMENT, symbol_short!("verify"), candidate.clone(), employer.clone()), document.doc_hash.clone());
+        
+        // First verification marks the document as verified
+        if !document.is_verified {
+            document.is_verified = true;
+            document.verified_by = Some(employer);
+            document.verified_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&doc_key, &document);
+        }
+        
+        Self::sync_profile(&env, &candidate);
+        
+        Ok(document)
+    }
+    
+    /// Candidate withdraws a document; it stays on-chain but no longer counts
+    pub fn retire_document(
+        env: Env,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+        reason: RevocationReason,
+    ) -> Result<Document, Error> {
+        candidate.require_auth();
         
-        // Mark document as verified
-        document.is_verified = true;
-        document.verified_by = Some(employer);
-        document.verified_at = Some(env.ledger().timestamp());
+        let doc_key = (candidate.clone(), doc_hash.clone());
+        let mut document: Document = env.storage().persistent()
+            .get(&doc_key)
+            .ok_or(Error::DocumentNotFound)?;
+        if document.is_retired {
+            return Err(Error::DocumentRetired);
+        }
         
+        document.is_retired = true;
         env.storage().persistent().set(&doc_key, &document);
         
-        // Increment verification count on profile
-        let profile_key = (candidate.clone(),);
-        if let Some(mut profile) = env.storage().persistent().get::<_, CandidateProfile>(&profile_key) {
-            profile.verification_count += 1;
-            if profile.verification_count >= 1 {
-                profile.is_verified = true;
+        Self::push_tombstone(&env, &candidate, &doc_hash, TombstoneKind::DocumentRetired, &candidate, reason);
+        env.events().publish((DOCUMENT, symbol_short!("retire"), candidate.clone()), (doc_hash, reason));
+        
+        Self::sync_profile(&env, &candidate);
+        Ok(document)
+    }
+    
+    /// Verifier withdraws a verification they made
+    pub fn revoke_verification(
+        env: Env,
+        verifier: Address,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+        reason: RevocationReason,
+    ) -> Result<Document, Error> {
+        verifier.require_auth();
+        
+        let doc_key = (candidate.clone(), doc_hash.clone());
+        let mut document: Document = env.storage().persistent()
+            .get(&doc_key)
+            .ok_or(Error::DocumentNotFound)?;
+        
+        let verifiers_key = (symbol_short!("doc_vrfs"), candidate.clone(), doc_hash.clone());
+        let mut verifiers: Vec<Address> = env.storage().persistent()
+            .get(&verifiers_key)
+            .unwrap_or(Vec::new(&env));
+        let index = verifiers.first_index_of(&verifier).ok_or(Error::VerificationNotFound)?;
+        verifiers.remove(index);
+        env.storage().persistent().set(&verifiers_key, &verifiers);
+        
+        // Hand the document over to a remaining verifier, or mark it unverified
+        match verifiers.first() {
+            Some(remaining) => {
+                if document.verified_by == Some(verifier.clone()) {
+                    document.verified_by = Some(remaining);
+                }
+            },
+            None => {
+                document.is_verified = false;
+                document.verified_by = None;
+                document.verified_at = None;
             }
-            env.storage().persistent().set(&profile_key, &profile);
         }
+        env.storage().persistent().set(&doc_key, &document);
+        
+        Self::push_tombstone(&env, &candidate, &doc_hash, TombstoneKind::VerificationRevoked, &verifier, reason);
+        env.events().publish((DOCUMENT, symbol_short!("revoke"), candidate.clone(), verifier), (doc_hash, reason));
         
-        document
+        Self::sync_profile(&env, &candidate);
+        Ok(document)
+    }
+    
+    /// Get retirement and revocation records for a document
+    pub fn get_document_tombstones(
+        env: Env,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+    ) -> Vec<Tombstone> {
+        env.storage().persistent()
+            .get(&(symbol_short!("tombs"), candidate, doc_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+    
+    /// Admin sets the policy used to decide `CandidateProfile.is_verified`
+    pub fn set_verification_policy(env: Env, policy: VerificationPolicy) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        if policy.min_documents == 0 || policy.min_verifiers == 0 {
+            return Err(Error::InvalidPolicy);
+        }
+        env.storage().persistent().set(&symbol_short!("policy"), &policy);
+        env.events().publish((CONFIG, symbol_short!("policy")), policy);
+        Ok(())
+    }
+    
+    /// Get the active verification policy (defaults to one verified document)
+    pub fn get_verification_policy(env: Env) -> VerificationPolicy {
+        env.storage().persistent()
+            .get(&symbol_short!("policy"))
+            .unwrap_or(VerificationPolicy {
+                min_verifiers: 1,
+                min_documents: 1,
+                required_doc_types: Vec::new(&env),
+            })
+    }
+    
+    /// Re-evaluate a profile against the current policy (e.g. after a policy change)
+    pub fn refresh_profile(env: Env, candidate: Address) -> Result<CandidateProfile, Error> {
+        Self::sync_profile(&env, &candidate).ok_or(Error::ProfileNotFound)
+    }
+    
+    /// Get the distinct verifiers of a document
+    pub fn get_document_verifiers(
+        env: Env,
+        candidate: Address,
+        doc_hash: BytesN<32>,
+    ) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&(symbol_short!("doc_vrfs"), candidate, doc_hash))
+            .unwrap_or(Vec::new(&env))
     }
     
     /// Get candidate profile
@@ -137,6 +478,22 @@ impl CandidateProfileContract {
         env.storage().persistent().get(&profile_key)
     }
     
+    /// Get the IPFS CID of a candidate's profile
+    pub fn get_profile_cid(env: Env, candidate: Address) -> Option<String> {
+        Self::get_profile(env, candidate).map(|profile| profile.ipfs_cid)
+    }
+    
+    /// Check if the candidate holds a current, verified document of a given type
+    pub fn has_verified_document_type(env: Env, candidate: Address, doc_type: String) -> bool {
+        let hashes: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&(symbol_short!("doc_type"), candidate.clone(), doc_type))
+            .unwrap_or(Vec::new(&env));
+        hashes.iter().any(|doc_hash| {
+            Self::get_document(env.clone(), candidate.clone(), doc_hash)
+                .is_some_and(|document| document.is_verified && !document.is_superseded && !document.is_retired)
+        })
+    }
+    
     /// Get specific document
     pub fn get_document(
         env: Env,
@@ -147,31 +504,245 @@ impl CandidateProfileContract {
         env.storage().persistent().get(&doc_key)
     }
     
-    /// Get all documents for a candidate (returns hashes)
+    /// Get a page of document hashes for a candidate, in upload order
     pub fn get_candidate_documents(
         env: Env,
         candidate: Address,
+        start: u32,
+        limit: u32,
     ) -> Vec<BytesN<32>> {
-        // Note: In production, you'd use a secondary index or events
-        // This is a simplified version
-        Vec::new(&env)
+        let hashes: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&(symbol_short!("docs"), candidate))
+            .unwrap_or(Vec::new(&env));
+        Self::paginate(&hashes, start, limit)
     }
     
-    /// Check if document hash matches (for tamper detection)
-    pub fn verify_document_integrity(
+    /// Get a page of a candidate's documents of a given type ("resume", "degree", ...)
+    pub fn get_documents_by_type(
         env: Env,
         candidate: Address,
+        doc_type: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Document> {
+        let hashes: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&(symbol_short!("doc_type"), candidate.clone(), doc_type))
+            .unwrap_or(Vec::new(&env));
+        
+        let mut documents = Vec::new(&env);
+        for doc_hash in Self::paginate(&hashes, start, limit).iter() {
+            if let Some(document) = Self::get_document(env.clone(), candidate.clone(), doc_hash) {
+                documents.push_back(document);
+            }
+        }
+        documents
+    }
+    
+    /// Total number of documents a candidate has added
+    pub fn get_document_count(env: Env, candidate: Address) -> u32 {
+        env.storage().persistent()
+            .get::<_, Vec<BytesN<32>>>(&(symbol_short!("docs"), candidate))
+            .map(|hashes| hashes.len())
+            .unwrap_or(0)
+    }
+    
+    /// Number of documents a candidate has added of a given type
+    pub fn get_document_count_by_type(env: Env, candidate: Address, doc_type: String) -> u32 {
+        env.storage().persistent()
+            .get::<_, Vec<BytesN<32>>>(&(symbol_short!("doc_type"), candidate, doc_type))
+            .map(|hashes| hashes.len())
+            .unwrap_or(0)
+    }
+    
+    /// Check if document hash matches (for tamper detection)
+    pub fn verify_document_integrity(
+        _env: Env,
+        _candidate: Address,
         doc_hash: BytesN<32>,
         current_hash: BytesN<32>,
     ) -> bool {
         doc_hash == current_hash
     }
+    
+    // Helper functions
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env.storage().persistent()
+            .get(&symbol_short!("admin"))
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+    
+    fn require_trusted_verifier(env: &Env, verifier: &Address, candidate: &Address) -> Result<(), Error> {
+        if verifier == candidate {
+            return Err(Error::SelfVerification);
+        }
+        
+        let registry: Address = env.storage().persistent()
+            .get(&symbol_short!("registry"))
+            .ok_or(Error::NotInitialized)?;
+        if !VerifierRegistryClient::new(env, &registry).is_active_verifier(verifier) {
+            return Err(Error::VerifierNotTrusted);
+        }
+        Ok(())
+    }
+    
+    fn push_index<K>(env: &Env, key: K, doc_hash: &BytesN<32>)
+    where
+        K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        let mut hashes: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        hashes.push_back(doc_hash.clone());
+        env.storage().persistent().set(&key, &hashes);
+    }
+    
+    fn store_new_document(
+        env: &Env,
+        candidate: &Address,
+        doc_hash: BytesN<32>,
+        doc_type: String,
+        ipfs_cid: String,
+        version: u32,
+        root_hash: BytesN<32>,
+    ) -> Result<Document, Error> {
+        // Documents are immutable once added; changes go through a new version
+        let doc_key = (candidate.clone(), doc_hash.clone());
+        if env.storage().persistent().has(&doc_key) {
+            return Err(Error::DocumentAlreadyExists);
+        }
+        
+        let document = Document {
+            doc_hash: doc_hash.clone(),
+            doc_type,
+            ipfs_cid,
+            is_verified: false,
+            verified_by: None,
+            verified_at: None,
+            version,
+            root_hash: root_hash.clone(),
+            is_superseded: false,
+            is_retired: false,
+            created_at: env.ledger().timestamp(),
+        };
+        
+        // Index by candidate, by (candidate, doc_type) and by version chain
+        Self::push_index(env, (symbol_short!("versions"), candidate.clone(), root_hash), &doc_hash);
+        Self::push_index(env, (symbol_short!("docs"), candidate.clone()), &doc_hash);
+        Self::push_index(env, (symbol_short!("doc_type"), candidate.clone(), document.doc_type.clone()), &doc_hash);
+        
+        env.storage().persistent().set(&doc_key, &document);
+        Ok(document)
+    }
+    
+    fn push_tombstone(
+        env: &Env,
+        candidate: &Address,
+        doc_hash: &BytesN<32>,
+        kind: TombstoneKind,
+        actor: &Address,
+        reason: RevocationReason,
+    ) {
+        let key = (symbol_short!("tombs"), candidate.clone(), doc_hash.clone());
+        let mut tombstones: Vec<Tombstone> = env.storage().persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        tombstones.push_back(Tombstone {
+            kind,
+            actor: actor.clone(),
+            reason,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&key, &tombstones);
+    }
+    
+    fn sync_profile(env: &Env, candidate: &Address) -> Option<CandidateProfile> {
+        let profile_key = (candidate.clone(),);
+        let mut profile: CandidateProfile = env.storage().persistent().get(&profile_key)?;
+        let was_verified = profile.is_verified;
+        Self::evaluate_profile(env, &mut profile);
+        env.storage().persistent().set(&profile_key, &profile);
+        
+        if profile.is_verified != was_verified {
+            env.events().publish((PROFILE, symbol_short!("verified"), candidate.clone()), profile.is_verified);
+        }
+        Some(profile)
+    }
+    
+    // Recompute verification counters and status from the candidate's documents
+    fn evaluate_profile(env: &Env, profile: &mut CandidateProfile) {
+        let hashes: Vec<BytesN<32>> = env.storage().persistent()
+            .get(&(symbol_short!("docs"), profile.wallet.clone()))
+            .unwrap_or(Vec::new(env));
+        
+        let mut verified_docs = 0u32;
+        let mut verifiers: Vec<Address> = Vec::new(env);
+        let mut verified_types: Vec<String> = Vec::new(env);
+        
+        for doc_hash in hashes.iter() {
+            let document: Document = match env.storage().persistent().get(&(profile.wallet.clone(), doc_hash.clone())) {
+                Some(document) => document,
+                None => continue,
+            };
+            if !document.is_verified || document.is_superseded || document.is_retired {
+                continue;
+            }
+            
+            verified_docs += 1;
+            if !verified_types.contains(&document.doc_type) {
+                verified_types.push_back(document.doc_type);
+            }
+            let doc_verifiers: Vec<Address> = env.storage().persistent()
+                .get(&(symbol_short!("doc_vrfs"), profile.wallet.clone(), doc_hash))
+                .unwrap_or(Vec::new(env));
+            for verifier in doc_verifiers.iter() {
+                if !verifiers.contains(&verifier) {
+                    verifiers.push_back(verifier);
+                }
+            }
+        }
+        
+        let policy = Self::get_verification_policy(env.clone());
+        let has_required_types = policy.required_doc_types.iter()
+            .all(|doc_type| verified_types.contains(&doc_type));
+        
+        profile.verification_count = verified_docs;
+        profile.verifier_count = verifiers.len();
+        profile.is_verified = verified_docs >= policy.min_documents
+            && verifiers.len() >= policy.min_verifiers
+            && has_required_types;
+    }
+    
+    fn paginate(hashes: &Vec<BytesN<32>>, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        let start = start.min(hashes.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(hashes.len());
+        hashes.slice(start..end)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use soroban_sdk::{testutils::{Address as _, Events}, Env, IntoVal, TryFromVal};
+    use verifier_registry::{VerifierCategory, VerifierRegistryContract, VerifierRegistryContractClient};
+    
+    fn setup_registry<'a>(env: &Env, admin: &Address) -> VerifierRegistryContractClient<'a> {
+        let registry_id = env.register_contract(None, VerifierRegistryContract);
+        let registry = VerifierRegistryContractClient::new(env, &registry_id);
+        registry.initialize(admin);
+        registry
+    }
+    
+    fn add_trusted_verifier(env: &Env, registry: &VerifierRegistryContractClient, verifier: &Address) {
+        registry.add_verifier(
+            verifier,
+            &VerifierCategory::Employer,
+            &String::from_str(env, "Acme Corp"),
+            &String::from_str(env, "acme.com"),
+            &String::from_str(env, "QmAcmeProfile"),
+        );
+    }
 
     #[test]
     fn test_register_profile() {
@@ -206,6 +777,11 @@ mod test {
         
         env.mock_all_auths();
         
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        
         // Add document
         let doc = client.add_document(&candidate, &doc_hash, &doc_type, &ipfs_cid);
         assert_eq!(doc.is_verified, false);
@@ -215,4 +791,397 @@ mod test {
         assert_eq!(verified_doc.is_verified, true);
         assert_eq!(verified_doc.verified_by, Some(employer));
     }
+    
+    #[test]
+    fn test_candidate_document_index() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let resume = String::from_str(&env, "resume");
+        let degree = String::from_str(&env, "degree");
+        
+        env.mock_all_auths();
+        
+        for i in 0..3u8 {
+            let doc_hash = BytesN::from_array(&env, &[i; 32]);
+            client.add_document(&candidate, &doc_hash, &resume, &String::from_str(&env, "QmResume"));
+        }
+        let degree_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.add_document(&candidate, &degree_hash, &degree, &String::from_str(&env, "QmDegree"));
+        
+        assert_eq!(client.get_document_count(&candidate), 4);
+        assert_eq!(client.get_document_count_by_type(&candidate, &resume), 3);
+        
+        // Pagination walks the index in upload order
+        let page = client.get_candidate_documents(&candidate, &2, &10);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap(), BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(page.get(1).unwrap(), degree_hash);
+        assert_eq!(client.get_candidate_documents(&candidate, &10, &10).len(), 0);
+        
+        let degrees = client.get_documents_by_type(&candidate, &degree, &0, &10);
+        assert_eq!(degrees.len(), 1);
+        assert_eq!(degrees.get(0).unwrap().doc_hash, degree_hash);
+    }
+    
+    #[test]
+    fn test_unregistered_verifier_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let second_wallet = Address::generate(&env);
+        let doc_hash = BytesN::from_array(&env, &[3u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        
+        client.add_document(&candidate, &doc_hash, &String::from_str(&env, "degree"), &String::from_str(&env, "QmDegree"));
+        assert_eq!(
+            client.try_verify_document(&second_wallet, &candidate, &doc_hash).err(),
+            Some(Ok(Error::VerifierNotTrusted))
+        );
+        assert_eq!(
+            client.try_verify_document(&candidate, &candidate, &doc_hash).err(),
+            Some(Ok(Error::SelfVerification))
+        );
+    }
+    
+    #[test]
+    fn test_suspended_verifier_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let doc_hash = BytesN::from_array(&env, &[4u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        registry.suspend_verifier(&employer);
+        
+        client.add_document(&candidate, &doc_hash, &String::from_str(&env, "degree"), &String::from_str(&env, "QmDegree"));
+        assert_eq!(
+            client.try_verify_document(&employer, &candidate, &doc_hash).err(),
+            Some(Ok(Error::VerifierNotTrusted))
+        );
+    }
+    
+    #[test]
+    fn test_duplicate_verification_is_idempotent() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let doc_hash = BytesN::from_array(&env, &[5u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        client.register_profile(&candidate, &String::from_str(&env, "QmProfile"), &BytesN::from_array(&env, &[0u8; 32]));
+        client.add_document(&candidate, &doc_hash, &String::from_str(&env, "degree"), &String::from_str(&env, "QmDegree"));
+        
+        client.verify_document(&employer, &candidate, &doc_hash);
+        client.verify_document(&employer, &candidate, &doc_hash);
+        
+        let profile = client.get_profile(&candidate).unwrap();
+        assert_eq!(profile.verification_count, 1);
+        assert_eq!(profile.verifier_count, 1);
+        assert_eq!(client.get_document_verifiers(&candidate, &doc_hash).len(), 1);
+    }
+    
+    #[test]
+    fn test_verification_policy_quorum() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let university = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let degree_hash = BytesN::from_array(&env, &[6u8; 32]);
+        let id_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let degree = String::from_str(&env, "degree");
+        let id = String::from_str(&env, "id");
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &university);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        let mut required_doc_types = Vec::new(&env);
+        required_doc_types.push_back(degree.clone());
+        required_doc_types.push_back(id.clone());
+        client.set_verification_policy(&VerificationPolicy {
+            min_verifiers: 2,
+            min_documents: 2,
+            required_doc_types,
+        });
+        
+        client.register_profile(&candidate, &String::from_str(&env, "QmProfile"), &BytesN::from_array(&env, &[0u8; 32]));
+        client.add_document(&candidate, &degree_hash, &degree, &String::from_str(&env, "QmDegree"));
+        client.add_document(&candidate, &id_hash, &id, &String::from_str(&env, "QmId"));
+        
+        // One verifier on both documents is not enough
+        client.verify_document(&university, &candidate, &degree_hash);
+        client.verify_document(&university, &candidate, &id_hash);
+        let profile = client.get_profile(&candidate).unwrap();
+        assert_eq!(profile.verification_count, 2);
+        assert!(!profile.is_verified);
+        
+        // A second distinct verifier meets the quorum
+        client.verify_document(&employer, &candidate, &id_hash);
+        let profile = client.get_profile(&candidate).unwrap();
+        assert_eq!(profile.verifier_count, 2);
+        assert!(profile.is_verified);
+    }
+    
+    #[test]
+    fn test_verified_document_cannot_be_overwritten() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let doc_hash = BytesN::from_array(&env, &[8u8; 32]);
+        let degree = String::from_str(&env, "degree");
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        client.add_document(&candidate, &doc_hash, &degree, &String::from_str(&env, "QmDegree"));
+        client.verify_document(&employer, &candidate, &doc_hash);
+        
+        // Swapping the IPFS CID under the same hash is refused
+        assert_eq!(
+            client.try_add_document(&candidate, &doc_hash, &degree, &String::from_str(&env, "QmForged")).err(),
+            Some(Ok(Error::DocumentAlreadyExists))
+        );
+    }
+    
+    #[test]
+    fn test_document_version_chain() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let v1 = BytesN::from_array(&env, &[10u8; 32]);
+        let v2 = BytesN::from_array(&env, &[11u8; 32]);
+        let v3 = BytesN::from_array(&env, &[12u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        client.add_document(&candidate, &v1, &String::from_str(&env, "resume"), &String::from_str(&env, "QmResume1"));
+        client.verify_document(&employer, &candidate, &v1);
+        
+        let second = client.add_document_version(&candidate, &v1, &v2, &String::from_str(&env, "QmResume2"));
+        assert_eq!(second.version, 2);
+        assert_eq!(second.root_hash, v1);
+        assert!(!second.is_verified);
+        client.add_document_version(&candidate, &v2, &v3, &String::from_str(&env, "QmResume3"));
+        
+        // The verified original is preserved, only linked forward
+        let original = client.get_document(&candidate, &v1).unwrap();
+        assert!(original.is_verified);
+        assert_eq!(original.verified_by, Some(employer));
+        assert!(original.is_superseded);
+        assert_eq!(client.get_supersedes(&candidate, &v1), None);
+        assert_eq!(client.get_superseded_by(&candidate, &v3), None);
+        
+        // Walk the chain forward from the original and back from the latest
+        let mut forward = Vec::new(&env);
+        let mut cursor = Some(v1.clone());
+        while let Some(hash) = cursor {
+            cursor = client.get_superseded_by(&candidate, &hash);
+            forward.push_back(hash);
+        }
+        assert_eq!(forward, Vec::from_array(&env, [v1.clone(), v2.clone(), v3.clone()]));
+        
+        let mut backward = Vec::new(&env);
+        let mut cursor = Some(v3.clone());
+        while let Some(hash) = cursor {
+            cursor = client.get_supersedes(&candidate, &hash);
+            backward.push_back(hash);
+        }
+        assert_eq!(backward, Vec::from_array(&env, [v3.clone(), v2.clone(), v1.clone()]));
+        
+        let history = client.get_document_history(&candidate, &v2);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(0).unwrap().doc_hash, v1);
+        assert_eq!(client.get_latest_version(&candidate, &v1).unwrap().doc_hash, v3);
+        
+        // Old versions cannot be branched
+        assert_eq!(
+            client.try_add_document_version(&candidate, &v1, &BytesN::from_array(&env, &[13u8; 32]), &String::from_str(&env, "QmBranch")).err(),
+            Some(Ok(Error::DocumentSuperseded))
+        );
+    }
+    
+    #[test]
+    fn test_revoke_verification_and_retire_document() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let university = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let degree_hash = BytesN::from_array(&env, &[14u8; 32]);
+        let cert_hash = BytesN::from_array(&env, &[15u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &university);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        client.register_profile(&candidate, &String::from_str(&env, "QmProfile"), &BytesN::from_array(&env, &[0u8; 32]));
+        client.add_document(&candidate, &degree_hash, &String::from_str(&env, "degree"), &String::from_str(&env, "QmDegree"));
+        client.add_document(&candidate, &cert_hash, &String::from_str(&env, "certificate"), &String::from_str(&env, "QmCert"));
+        client.verify_document(&university, &candidate, &degree_hash);
+        client.verify_document(&employer, &candidate, &degree_hash);
+        client.verify_document(&employer, &candidate, &cert_hash);
+        assert_eq!(client.get_profile(&candidate).unwrap().verification_count, 2);
+        
+        // Revoking one of two verifications keeps the document verified
+        let degree = client.revoke_verification(&university, &candidate, &degree_hash, &RevocationReason::IssuedInError);
+        assert!(degree.is_verified);
+        assert_eq!(degree.verified_by, Some(employer.clone()));
+        
+        // Revoking the last one clears it
+        let degree = client.revoke_verification(&employer, &candidate, &degree_hash, &RevocationReason::Fraudulent);
+        assert!(!degree.is_verified);
+        assert_eq!(client.get_profile(&candidate).unwrap().verification_count, 1);
+        assert_eq!(client.get_document_tombstones(&candidate, &degree_hash).len(), 2);
+        
+        // Retiring the certificate drops it from the profile but keeps the record
+        client.retire_document(&candidate, &cert_hash, &RevocationReason::Outdated);
+        let profile = client.get_profile(&candidate).unwrap();
+        assert_eq!(profile.verification_count, 0);
+        assert!(!profile.is_verified);
+        assert!(client.get_document(&candidate, &cert_hash).unwrap().is_retired);
+        
+        let tombstone = client.get_document_tombstones(&candidate, &cert_hash).get(0).unwrap();
+        assert_eq!(tombstone.kind, TombstoneKind::DocumentRetired);
+        assert_eq!(tombstone.actor, candidate);
+        
+        // Retired documents cannot be verified again
+        assert_eq!(
+            client.try_verify_document(&university, &candidate, &cert_hash).err(),
+            Some(Ok(Error::DocumentRetired))
+        );
+    }
+    
+    #[test]
+    fn test_events_emitted() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let candidate = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let doc_hash = BytesN::from_array(&env, &[16u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &employer);
+        
+        client.register_profile(&candidate, &String::from_str(&env, "QmProfile"), &BytesN::from_array(&env, &[0u8; 32]));
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (PROFILE, symbol_short!("register"), candidate.clone()).into_val(&env));
+        
+        client.add_document(&candidate, &doc_hash, &String::from_str(&env, "degree"), &String::from_str(&env, "QmDegree"));
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (DOCUMENT, symbol_short!("add"), candidate.clone()).into_val(&env));
+        
+        // Verification emits the document event followed by the profile status change
+        client.verify_document(&employer, &candidate, &doc_hash);
+        let events = env.events().all();
+        let (_, topics, _) = events.get(events.len() - 2).unwrap();
+        assert_eq!(topics, (DOCUMENT, symbol_short!("verify"), candidate.clone(), employer.clone()).into_val(&env));
+        let (_, topics, data) = events.last().unwrap();
+        assert_eq!(topics, (PROFILE, symbol_short!("verified"), candidate.clone()).into_val(&env));
+        assert!(bool::try_from_val(&env, &data).unwrap());
+        
+        // A duplicate verification changes nothing and emits nothing
+        let before = env.events().all().len();
+        client.verify_document(&employer, &candidate, &doc_hash);
+        assert_eq!(env.events().all().len(), before);
+    }
+    
+    #[contract]
+    struct MockJobApplication;
+    
+    #[contractimpl]
+    impl MockJobApplication {
+        pub fn get_reputation(env: Env, _address: Address) -> Reputation {
+            Reputation {
+                count: 2,
+                total_score: 9,
+                average: 450,
+                recent: Vec::from_array(&env, [4, 5]),
+                recent_average: 450,
+                updated_at: 0,
+            }
+        }
+    }
+    
+    #[test]
+    fn test_reputation_from_job_application() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, CandidateProfileContract);
+        let client = CandidateProfileContractClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        assert_eq!(client.get_reputation(&candidate), None);
+        
+        let jobs = env.register_contract(None, MockJobApplication);
+        client.set_job_application(&jobs);
+        let reputation = client.get_reputation(&candidate).unwrap();
+        assert_eq!(reputation.count, 2);
+        assert_eq!(reputation.average, 450);
+    }
 }
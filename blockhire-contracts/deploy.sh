@@ -28,11 +28,29 @@ stellar contract optimize \
   --wasm target/wasm32-unknown-unknown/release/document_verification.wasm \
   --wasm-out target/wasm32-unknown-unknown/release/document_verification_optimized.wasm
 
+stellar contract optimize \
+  --wasm target/wasm32-unknown-unknown/release/verifier_registry.wasm \
+  --wasm-out target/wasm32-unknown-unknown/release/verifier_registry_optimized.wasm
+
 echo "✅ WASM files optimized!"
 
 # Deploy to testnet
 echo "🌐 Deploying to Stellar Testnet..."
 
+# Deploy Verifier Registry Contract
+echo "Deploying Verifier Registry Contract..."
+REGISTRY_CONTRACT_ID=$(stellar contract deploy \
+  --wasm target/wasm32-unknown-unknown/release/verifier_registry_optimized.wasm \
+  --source-account default \
+  --network testnet)
+
+ADMIN_ADDRESS=$(stellar keys address default)
+
+stellar contract invoke --id $REGISTRY_CONTRACT_ID --source-account default --network testnet \
+  -- initialize --admin $ADMIN_ADDRESS
+
+echo "✅ Verifier Registry Contract: $REGISTRY_CONTRACT_ID"
+
 # Deploy Candidate Profile Contract
 echo "Deploying Candidate Profile Contract..."
 CANDIDATE_CONTRACT_ID=$(stellar contract deploy \
@@ -40,6 +58,9 @@ CANDIDATE_CONTRACT_ID=$(stellar contract deploy \
   --source-account default \
   --network testnet)
 
+stellar contract invoke --id $CANDIDATE_CONTRACT_ID --source-account default --network testnet \
+  -- initialize --admin $ADMIN_ADDRESS --verifier_registry $REGISTRY_CONTRACT_ID
+
 echo "✅ Candidate Profile Contract: $CANDIDATE_CONTRACT_ID"
 
 # Deploy Job Application Contract
@@ -58,8 +79,19 @@ DOC_CONTRACT_ID=$(stellar contract deploy \
   --source-account default \
   --network testnet)
 
+stellar contract invoke --id $DOC_CONTRACT_ID --source-account default --network testnet \
+  -- initialize --admin $ADMIN_ADDRESS --verifier_registry $REGISTRY_CONTRACT_ID
+
 echo "✅ Document Verification Contract: $DOC_CONTRACT_ID"
 
+# Job requirements are checked against the profile and verification contracts
+stellar contract invoke --id $JOB_CONTRACT_ID --source-account default --network testnet \
+  -- initialize --admin $ADMIN_ADDRESS --candidate_profile $CANDIDATE_CONTRACT_ID --document_verification $DOC_CONTRACT_ID
+
+# Candidate profiles expose the post-hire reputation kept by job-application
+stellar contract invoke --id $CANDIDATE_CONTRACT_ID --source-account default --network testnet \
+  -- set_job_application --job_application $JOB_CONTRACT_ID
+
 # Save contract IDs to .env file
 echo "💾 Saving contract IDs..."
 
@@ -71,6 +103,7 @@ cat > .env.contracts << EOF
 NEXT_PUBLIC_CANDIDATE_CONTRACT_ID=$CANDIDATE_CONTRACT_ID
 NEXT_PUBLIC_JOB_CONTRACT_ID=$JOB_CONTRACT_ID
 NEXT_PUBLIC_DOC_VERIFICATION_CONTRACT_ID=$DOC_CONTRACT_ID
+NEXT_PUBLIC_VERIFIER_REGISTRY_CONTRACT_ID=$REGISTRY_CONTRACT_ID
 NEXT_PUBLIC_STELLAR_NETWORK=testnet
 NEXT_PUBLIC_HORIZON_URL=https://horizon-testnet.stellar.org
 EOF
@@ -81,5 +114,6 @@ echo "📋 Contract Addresses:"
 echo "   Candidate Profile: $CANDIDATE_CONTRACT_ID"
 echo "   Job Application: $JOB_CONTRACT_ID"
 echo "   Document Verification: $DOC_CONTRACT_ID"
+echo "   Verifier Registry: $REGISTRY_CONTRACT_ID"
 echo ""
 echo "🎉 Deployment complete! Copy .env.contracts content to your .env.local file"
@@ -1,5 +1,24 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, BytesN, Vec, symbol_short};
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, Address, Env, String, BytesN, Symbol, Vec, symbol_short};
+
+// Event topics (listed in the README)
+const RECORD: Symbol = symbol_short!("record");        // Verification records
+const DOCUMENT: Symbol = symbol_short!("document");    // Retired documents
+const CONFIG: Symbol = symbol_short!("config");        // Admin wiring
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    NotAuthorized = 3,
+    RecordNotFound = 4,
+    AlreadyRevoked = 5,
+    AlreadyRetired = 6,
+    VerifierNotTrusted = 7,
+    SelfVerification = 8,
+}
 
 // Immutable verification record
 #[contracttype]
@@ -15,12 +34,65 @@ pub struct VerificationRecord {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RevocationReason {
+    IssuedInError,
+    Expired,
+    Fraudulent,
+    Outdated,
+    Other,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TombstoneKind {
+    DocumentRetired,
+    VerificationRevoked,
+}
+
+// Permanent record of a retirement or revoked verification
+#[contracttype]
+#[derive(Clone)]
+pub struct Tombstone {
+    pub kind: TombstoneKind,
+    pub actor: Address,             // Candidate or verifier who withdrew
+    pub reason: RevocationReason,
+    pub timestamp: u64,
+}
+
+// Interface of the deployed verifier-registry contract
+#[contractclient(name = "VerifierRegistryClient")]
+pub trait VerifierRegistry {
+    fn is_active_verifier(env: Env, verifier: Address) -> bool;
+}
+
 #[contract]
 pub struct DocumentVerificationContract;
 
 #[contractimpl]
 impl DocumentVerificationContract {
     
+    /// One-time setup of the contract admin and the verifier registry to consult
+    pub fn initialize(env: Env, admin: Address, verifier_registry: Address) -> Result<(), Error> {
+        if env.storage().persistent().has(&symbol_short!("admin")) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().persistent().set(&symbol_short!("admin"), &admin);
+        env.storage().persistent().set(&symbol_short!("registry"), &verifier_registry);
+        env.events().publish((CONFIG, symbol_short!("init")), (admin, verifier_registry));
+        Ok(())
+    }
+    
+    /// Admin points the contract at a different verifier registry
+    pub fn set_verifier_registry(env: Env, verifier_registry: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().persistent().set(&symbol_short!("registry"), &verifier_registry);
+        env.events().publish((CONFIG, symbol_short!("registry")), verifier_registry);
+        Ok(())
+    }
+    
     /// Employer creates a verification record for a document
     pub fn verify_document(
         env: Env,
@@ -30,8 +102,9 @@ impl DocumentVerificationContract {
         doc_type: String,
         is_valid: bool,
         notes: String,
-    ) -> VerificationRecord {
+    ) -> Result<VerificationRecord, Error> {
         verifier.require_auth();
+        Self::require_trusted_verifier(&env, &verifier, &candidate)?;
         
         let record_id = Self::get_next_record_id(&env);
         
@@ -68,7 +141,88 @@ impl DocumentVerificationContract {
         // Update counter
         env.storage().persistent().set(&symbol_short!("rec_cnt"), &(record_id + 1));
         
-        record
+        env.events().publish((RECORD, symbol_short!("create"), candidate, verifier), record.clone());
+        
+        Ok(record)
+    }
+    
+    /// Verifier revokes a verification record they created
+    pub fn revoke_verification(
+        env: Env,
+        verifier: Address,
+        record_id: u64,
+        reason: RevocationReason,
+    ) -> Result<Tombstone, Error> {
+        verifier.require_auth();
+        
+        let record = Self::get_record(env.clone(), record_id).ok_or(Error::RecordNotFound)?;
+        if record.verifier != verifier {
+            return Err(Error::NotAuthorized);
+        }
+        
+        let key = (symbol_short!("rec_revk"), record_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyRevoked);
+        }
+        
+        let tombstone = Tombstone {
+            kind: TombstoneKind::VerificationRevoked,
+            actor: verifier.clone(),
+            reason,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &tombstone);
+        env.events().publish((RECORD, symbol_short!("revoke"), record.candidate, verifier), (record_id, reason));
+        
+        Ok(tombstone)
+    }
+    
+    /// Candidate retires a document; its verifications stop counting
+    pub fn retire_document(
+        env: Env,
+        candidate: Address,
+        document_hash: BytesN<32>,
+        reason: RevocationReason,
+    ) -> Result<Tombstone, Error> {
+        candidate.require_auth();
+        
+        let key = (symbol_short!("retired"), candidate.clone(), document_hash.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyRetired);
+        }
+        
+        let tombstone = Tombstone {
+            kind: TombstoneKind::DocumentRetired,
+            actor: candidate.clone(),
+            reason,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &tombstone);
+        env.events().publish((DOCUMENT, symbol_short!("retire"), candidate), (document_hash, reason));
+        
+        Ok(tombstone)
+    }
+    
+    /// Get the revocation of a verification record, if any
+    pub fn get_revocation(env: Env, record_id: u64) -> Option<Tombstone> {
+        env.storage().persistent().get(&(symbol_short!("rec_revk"), record_id))
+    }
+    
+    /// Get the retirement of a candidate's document, if any
+    pub fn get_retirement(
+        env: Env,
+        candidate: Address,
+        document_hash: BytesN<32>,
+    ) -> Option<Tombstone> {
+        env.storage().persistent().get(&(symbol_short!("retired"), candidate, document_hash))
+    }
+    
+    /// Check if a record is valid and neither revoked nor retired
+    pub fn is_record_active(env: Env, record_id: u64) -> bool {
+        match Self::get_record(env.clone(), record_id) {
+            Some(record) => Self::counts(&env, &record),
+            None => false,
+        }
     }
     
     /// Get verification record by ID
@@ -114,30 +268,30 @@ impl DocumentVerificationContract {
         records
     }
     
-    /// Check if a document has been verified (at least one valid verification)
+    /// Check if a document has been verified (at least one valid, unrevoked verification)
     pub fn is_document_verified(
         env: Env,
         document_hash: BytesN<32>,
     ) -> bool {
-        let verifications = Self::get_verifications_by_hash(env, document_hash);
+        let verifications = Self::get_verifications_by_hash(env.clone(), document_hash);
         
         for record in verifications.iter() {
-            if record.verification_status {
+            if Self::counts(&env, &record) {
                 return true;
             }
         }
         false
     }
     
-    /// Get verification count for a candidate
+    /// Get count of valid, unrevoked verifications for a candidate
     pub fn get_verification_count(
         env: Env,
         candidate: Address,
     ) -> u32 {
-        let records = Self::get_candidate_verifications(env, candidate);
+        let records = Self::get_candidate_verifications(env.clone(), candidate);
         let mut count = 0u32;
         for record in records.iter() {
-            if record.verification_status {
+            if Self::counts(&env, &record) {
                 count += 1;
             }
         }
@@ -146,7 +300,7 @@ impl DocumentVerificationContract {
     
     /// Detect if document has been tampered (hash mismatch)
     pub fn detect_tampering(
-        env: Env,
+        _env: Env,
         original_hash: BytesN<32>,
         current_hash: BytesN<32>,
     ) -> bool {
@@ -154,7 +308,36 @@ impl DocumentVerificationContract {
         original_hash != current_hash
     }
     
-    // Helper function
+    // Helper functions
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env.storage().persistent()
+            .get(&symbol_short!("admin"))
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+    
+    // A record counts if it is valid and neither revoked nor retired
+    fn counts(env: &Env, record: &VerificationRecord) -> bool {
+        record.verification_status
+            && !env.storage().persistent().has(&(symbol_short!("rec_revk"), record.record_id))
+            && !env.storage().persistent().has(&(symbol_short!("retired"), record.candidate.clone(), record.document_hash.clone()))
+    }
+    
+    fn require_trusted_verifier(env: &Env, verifier: &Address, candidate: &Address) -> Result<(), Error> {
+        if verifier == candidate {
+            return Err(Error::SelfVerification);
+        }
+        
+        let registry: Address = env.storage().persistent()
+            .get(&symbol_short!("registry"))
+            .ok_or(Error::NotInitialized)?;
+        if !VerifierRegistryClient::new(env, &registry).is_active_verifier(verifier) {
+            return Err(Error::VerifierNotTrusted);
+        }
+        Ok(())
+    }
+    
     fn get_next_record_id(env: &Env) -> u64 {
         env.storage().persistent()
             .get(&symbol_short!("rec_cnt"))
@@ -165,7 +348,25 @@ impl DocumentVerificationContract {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use soroban_sdk::{testutils::{Address as _, Events}, Env, IntoVal};
+    use verifier_registry::{VerifierCategory, VerifierRegistryContract, VerifierRegistryContractClient};
+    
+    fn setup_registry<'a>(env: &Env, admin: &Address) -> VerifierRegistryContractClient<'a> {
+        let registry_id = env.register_contract(None, VerifierRegistryContract);
+        let registry = VerifierRegistryContractClient::new(env, &registry_id);
+        registry.initialize(admin);
+        registry
+    }
+    
+    fn add_trusted_verifier(env: &Env, registry: &VerifierRegistryContractClient, verifier: &Address) {
+        registry.add_verifier(
+            verifier,
+            &VerifierCategory::Employer,
+            &String::from_str(env, "Acme Corp"),
+            &String::from_str(env, "acme.com"),
+            &String::from_str(env, "QmAcmeProfile"),
+        );
+    }
 
     #[test]
     fn test_document_verification() {
@@ -179,6 +380,11 @@ mod test {
         
         env.mock_all_auths();
         
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &verifier);
+        
         let record = client.verify_document(
             &verifier,
             &candidate,
@@ -191,6 +397,9 @@ mod test {
         assert_eq!(record.verification_status, true);
         assert_eq!(record.verifier, verifier);
         
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (RECORD, symbol_short!("create"), candidate.clone(), verifier.clone()).into_val(&env));
+        
         // Check if document is verified
         let is_verified = client.is_document_verified(&doc_hash);
         assert_eq!(is_verified, true);
@@ -211,4 +420,89 @@ mod test {
         let not_tampered = client.detect_tampering(&original_hash, &original_hash);
         assert_eq!(not_tampered, false);
     }
+    
+    #[test]
+    fn test_unregistered_verifier_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, DocumentVerificationContract);
+        let client = DocumentVerificationContractClient::new(&env, &contract_id);
+        
+        let verifier = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        
+        let result = client.try_verify_document(
+            &verifier,
+            &candidate,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &String::from_str(&env, "degree"),
+            &true,
+            &String::from_str(&env, "Self-attested"),
+        );
+        assert_eq!(result.err(), Some(Ok(Error::VerifierNotTrusted)));
+    }
+    
+    #[test]
+    fn test_revocation_and_retirement() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, DocumentVerificationContract);
+        let client = DocumentVerificationContractClient::new(&env, &contract_id);
+        
+        let verifier = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let degree_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let cert_hash = BytesN::from_array(&env, &[2u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let admin = Address::generate(&env);
+        let registry = setup_registry(&env, &admin);
+        client.initialize(&admin, &registry.address);
+        add_trusted_verifier(&env, &registry, &verifier);
+        
+        let degree = client.verify_document(
+            &verifier,
+            &candidate,
+            &degree_hash,
+            &String::from_str(&env, "degree"),
+            &true,
+            &String::from_str(&env, "Degree verified"),
+        );
+        client.verify_document(
+            &verifier,
+            &candidate,
+            &cert_hash,
+            &String::from_str(&env, "certificate"),
+            &true,
+            &String::from_str(&env, "Certificate verified"),
+        );
+        assert_eq!(client.get_verification_count(&candidate), 2);
+        
+        // Only the issuing verifier can revoke
+        assert_eq!(
+            client.try_revoke_verification(&Address::generate(&env), &degree.record_id, &RevocationReason::Other).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        
+        client.revoke_verification(&verifier, &degree.record_id, &RevocationReason::IssuedInError);
+        assert!(!client.is_document_verified(&degree_hash));
+        assert!(!client.is_record_active(&degree.record_id));
+        assert_eq!(client.get_revocation(&degree.record_id).unwrap().reason, RevocationReason::IssuedInError);
+        assert_eq!(
+            client.try_revoke_verification(&verifier, &degree.record_id, &RevocationReason::Other).err(),
+            Some(Ok(Error::AlreadyRevoked))
+        );
+        
+        // Record itself stays in the audit trail
+        assert_eq!(client.get_verifications_by_hash(&degree_hash).len(), 1);
+        
+        client.retire_document(&candidate, &cert_hash, &RevocationReason::Expired);
+        assert!(!client.is_document_verified(&cert_hash));
+        assert_eq!(client.get_verification_count(&candidate), 0);
+    }
 }
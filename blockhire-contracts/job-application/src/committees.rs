@@ -0,0 +1,299 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::{Application, ApplicationStatus, Error, JobApplicationContract, JobApplicationContractClient};
+
+// Event topic for hiring committee changes
+const COMMITTEE: Symbol = symbol_short!("committee");
+
+// Panel that must sign off before an application on the job can be accepted
+#[contracttype]
+#[derive(Clone)]
+pub struct HiringCommittee {
+    pub job_id: u64,
+    pub members: Vec<Address>,
+    pub quorum: u32,                // Approvals needed before accept_application
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Vote {
+    Approve,
+    Reject,
+}
+
+// One member's vote on an application; the rationale hash is kept under its own key
+#[contracttype]
+#[derive(Clone)]
+pub struct CommitteeVote {
+    pub application_id: u64,
+    pub member: Address,
+    pub vote: Vote,
+    pub timestamp: u64,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer (or organization recruiter) attaches or replaces a job's hiring committee, which may not include
+    /// themselves or the poster. The panel is fixed once anyone has voted or any application has left Pending
+    pub fn set_hiring_committee(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        members: Vec<Address>,
+        quorum: u32,
+    ) -> Result<HiringCommittee, Error> {
+        employer.require_auth();
+        
+        let job = Self::load_managed_job(&env, &employer, job_id)?;
+        if members.contains(&employer) || members.contains(&job.employer) {
+            return Err(Error::NotAuthorized);
+        }
+        if Self::committee_locked(&env, job_id) {
+            return Err(Error::CommitteeLocked);
+        }
+        let mut unique = Vec::new(&env);
+        for member in members.iter() {
+            if !unique.contains(&member) {
+                unique.push_back(member);
+            }
+        }
+        if quorum == 0 || quorum > unique.len() {
+            return Err(Error::InvalidQuorum);
+        }
+        
+        let committee = HiringCommittee {
+            job_id: job.job_id,
+            members: unique,
+            quorum,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&(symbol_short!("cmte"), job_id), &committee);
+        
+        env.events().publish((COMMITTEE, symbol_short!("set"), job_id), (committee.members.len(), quorum));
+        Ok(committee)
+    }
+    
+    /// Employer drops the committee before it is locked; accept_application is single-signature again
+    pub fn remove_hiring_committee(env: Env, employer: Address, job_id: u64) -> Result<(), Error> {
+        employer.require_auth();
+        
+        Self::load_managed_job(&env, &employer, job_id)?;
+        let key = (symbol_short!("cmte"), job_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::CommitteeNotFound);
+        }
+        if Self::committee_locked(&env, job_id) {
+            return Err(Error::CommitteeLocked);
+        }
+        env.storage().persistent().remove(&key);
+        
+        env.events().publish((COMMITTEE, symbol_short!("remove"), job_id), ());
+        Ok(())
+    }
+    
+    /// Committee member votes once on an open application, with an optional rationale hash
+    pub fn cast_committee_vote(
+        env: Env,
+        member: Address,
+        application_id: u64,
+        vote: Vote,
+        rationale_hash: Option<BytesN<32>>,
+    ) -> Result<CommitteeVote, Error> {
+        member.require_auth();
+        
+        let application: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), application_id))
+            .ok_or(Error::ApplicationNotFound)?;
+        let committee = Self::get_hiring_committee(env.clone(), application.job_id)
+            .ok_or(Error::CommitteeNotFound)?;
+        if !committee.members.contains(&member) {
+            return Err(Error::NotAuthorized);
+        }
+        if application.status.is_terminal() {
+            return Err(Error::InvalidTransition);
+        }
+        
+        let vote_key = (symbol_short!("vote"), application_id, member.clone());
+        if env.storage().persistent().has(&vote_key) {
+            return Err(Error::AlreadyVoted);
+        }
+        
+        let record = CommitteeVote {
+            application_id,
+            member: member.clone(),
+            vote,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&vote_key, &record);
+        if let Some(hash) = rationale_hash {
+            env.storage().persistent().set(&(symbol_short!("vote_why"), application_id, member.clone()), &hash);
+        }
+        let votes_key = (symbol_short!("votes"), application_id);
+        let mut votes: Vec<Address> = env.storage().persistent().get(&votes_key).unwrap_or(Vec::new(&env));
+        votes.push_back(member.clone());
+        env.storage().persistent().set(&votes_key, &votes);
+        
+        env.events().publish((COMMITTEE, symbol_short!("vote"), application_id), (member, vote));
+        Ok(record)
+    }
+    
+    /// Get a job's hiring committee
+    pub fn get_hiring_committee(env: Env, job_id: u64) -> Option<HiringCommittee> {
+        env.storage().persistent().get(&(symbol_short!("cmte"), job_id))
+    }
+    
+    /// Get every vote cast on an application, in casting order
+    pub fn get_committee_votes(env: Env, application_id: u64) -> Vec<CommitteeVote> {
+        let members: Vec<Address> = env.storage().persistent()
+            .get(&(symbol_short!("votes"), application_id))
+            .unwrap_or(Vec::new(&env));
+        let mut votes = Vec::new(&env);
+        for member in members.iter() {
+            if let Some(vote) = env.storage().persistent().get(&(symbol_short!("vote"), application_id, member)) {
+                votes.push_back(vote);
+            }
+        }
+        votes
+    }
+    
+    /// Get the rationale hash a member attached to their vote
+    pub fn get_vote_rationale(env: Env, application_id: u64, member: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(symbol_short!("vote_why"), application_id, member))
+    }
+    
+    /// Approvals and rejections from the job's current committee members
+    pub fn get_vote_tally(env: Env, application_id: u64) -> (u32, u32) {
+        let committee = env.storage().persistent()
+            .get::<_, Application>(&(symbol_short!("app"), application_id))
+            .and_then(|application| Self::get_hiring_committee(env.clone(), application.job_id));
+        let mut approvals = 0;
+        let mut rejections = 0;
+        if let Some(committee) = committee {
+            for member in committee.members.iter() {
+                let vote: Option<CommitteeVote> = env.storage().persistent()
+                    .get(&(symbol_short!("vote"), application_id, member));
+                match vote.map(|vote| vote.vote) {
+                    Some(Vote::Approve) => approvals += 1,
+                    Some(Vote::Reject) => rejections += 1,
+                    None => {}
+                }
+            }
+        }
+        (approvals, rejections)
+    }
+    
+    // Helper functions
+    fn committee_locked(env: &Env, job_id: u64) -> bool {
+        Self::get_index(env, (symbol_short!("job_apps"), job_id)).iter().any(|application_id| {
+            env.storage().persistent().has(&(symbol_short!("votes"), application_id))
+                || Self::get_application(env.clone(), application_id)
+                    .is_some_and(|application| application.status != ApplicationStatus::Pending)
+        })
+    }
+    
+    pub(crate) fn require_quorum(env: &Env, application: &Application) -> Result<(), Error> {
+        match Self::get_hiring_committee(env.clone(), application.job_id) {
+            Some(committee) if Self::get_vote_tally(env.clone(), application.application_id).0 < committee.quorum => {
+                Err(Error::QuorumNotMet)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compensation, Currency, PayPeriod};
+    use soroban_sdk::{testutils::Address as _, vec, String};
+    
+    #[test]
+    fn test_committee_quorum_gates_accept() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let rationale = BytesN::from_array(&env, &[7u8; 32]);
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 80_000,
+                max: 80_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        assert_eq!(
+            client.try_remove_hiring_committee(&employer, &1).err(),
+            Some(Ok(Error::CommitteeNotFound))
+        );
+        
+        // Quorum must fit the (deduplicated) panel, and the employer cannot sit on it
+        let members = vec![&env, alice.clone(), bob.clone(), carol.clone(), alice.clone()];
+        assert_eq!(client.try_set_hiring_committee(&employer, &1, &members, &4).err(), Some(Ok(Error::InvalidQuorum)));
+        assert_eq!(client.try_set_hiring_committee(&employer, &1, &members, &0).err(), Some(Ok(Error::InvalidQuorum)));
+        assert_eq!(client.try_set_hiring_committee(&outsider, &1, &members, &2).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(
+            client.try_set_hiring_committee(&employer, &1, &vec![&env, alice.clone(), employer.clone()], &1).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        assert_eq!(client.set_hiring_committee(&employer, &1, &members, &2).members.len(), 3);
+        client.review_application(&employer, &1);
+        
+        // Only members vote, once each
+        assert_eq!(
+            client.try_cast_committee_vote(&outsider, &1, &Vote::Approve, &None).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        client.cast_committee_vote(&alice, &1, &Vote::Approve, &Some(rationale.clone()));
+        client.cast_committee_vote(&bob, &1, &Vote::Reject, &None);
+        assert_eq!(
+            client.try_cast_committee_vote(&alice, &1, &Vote::Reject, &None).err(),
+            Some(Ok(Error::AlreadyVoted))
+        );
+        assert_eq!(client.try_accept_application(&employer, &1).err(), Some(Ok(Error::QuorumNotMet)));
+        
+        client.cast_committee_vote(&carol, &1, &Vote::Approve, &None);
+        assert_eq!(client.get_vote_tally(&1), (2, 1));
+        assert_eq!(client.accept_application(&employer, &1).status, ApplicationStatus::Accepted);
+        
+        // Every vote stays on record, rejections included
+        let votes = client.get_committee_votes(&1);
+        assert_eq!(votes.len(), 3);
+        assert_eq!(votes.get(1).unwrap().vote, Vote::Reject);
+        assert_eq!(client.get_vote_rationale(&1, &alice), Some(rationale));
+        assert_eq!(client.get_vote_rationale(&1, &bob), None);
+        
+        // The panel that voted stays in charge of the job
+        assert_eq!(
+            client.try_remove_hiring_committee(&employer, &1).err(),
+            Some(Ok(Error::CommitteeLocked))
+        );
+        assert_eq!(
+            client.try_set_hiring_committee(&employer, &1, &vec![&env, outsider.clone()], &1).err(),
+            Some(Ok(Error::CommitteeLocked))
+        );
+        let other = Address::generate(&env);
+        client.apply_to_job(&other, &1, &String::from_str(&env, "QmCover"), &None);
+        client.review_application(&employer, &2);
+        assert_eq!(client.try_accept_application(&employer, &2).err(), Some(Ok(Error::QuorumNotMet)));
+    }
+}
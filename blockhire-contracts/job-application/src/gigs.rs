@@ -0,0 +1,360 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
+
+use crate::{Application, ApplicationStatus, Error, JobApplicationContract, JobApplicationContractClient};
+
+// Event topic for gig engagement changes
+const GIG: Symbol = symbol_short!("gig");
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MilestoneStatus {
+    Funded,
+    Submitted,      // Deliverable waiting for employer approval
+    Released,       // Paid to the candidate
+    Disputed,       // Funds locked until the arbitrator rules
+    Resolved,       // Split by the arbitrator; see `candidate_share`
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum EngagementStatus {
+    Active,
+    Completed,      // Every milestone paid out
+}
+
+// Milestone as agreed when the engagement starts
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneTerms {
+    pub description_cid: String,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Milestone {
+    pub description_cid: String,
+    pub amount: i128,
+    pub status: MilestoneStatus,
+    pub deliverable_cid: Option<String>,
+    pub dispute_cid: Option<String>,  // Reason for the dispute on IPFS
+    pub candidate_share: i128,        // Amount paid to the candidate once settled
+    pub updated_at: u64,
+}
+
+// Contract engagement for an accepted application, funded upfront
+#[contracttype]
+#[derive(Clone)]
+pub struct Engagement {
+    pub engagement_id: u64,
+    pub application_id: u64,
+    pub employer: Address,
+    pub candidate: Address,
+    pub arbitrator: Address,        // Settles disputed milestones
+    pub token: Address,
+    pub milestones: Vec<Milestone>,
+    pub status: EngagementStatus,
+    pub created_at: u64,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer (or organization recruiter) turns an accepted application into a milestone engagement, escrowing every milestone upfront
+    pub fn start_engagement(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+        token: Address,
+        arbitrator: Address,
+        terms: Vec<MilestoneTerms>,
+    ) -> Result<Engagement, Error> {
+        employer.require_auth();
+        
+        let application: Application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if !matches!(application.status, ApplicationStatus::Accepted | ApplicationStatus::Hired) {
+            return Err(Error::InvalidTransition);
+        }
+        let engagement_key = (symbol_short!("app_gig"), application_id);
+        if env.storage().persistent().has(&engagement_key) {
+            return Err(Error::InvalidTransition);
+        }
+        if arbitrator == application.candidate || Self::load_for_reviewer(&env, &arbitrator, application_id).is_ok() {
+            return Err(Error::InvalidArbitrator);
+        }
+        if terms.is_empty() || terms.iter().any(|milestone| milestone.amount <= 0) {
+            return Err(Error::InvalidDeposit);
+        }
+        
+        let timestamp = env.ledger().timestamp();
+        let mut milestones = Vec::new(&env);
+        let mut total: i128 = 0;
+        for milestone in terms.iter() {
+            total += milestone.amount;
+            milestones.push_back(Milestone {
+                description_cid: milestone.description_cid,
+                amount: milestone.amount,
+                status: MilestoneStatus::Funded,
+                deliverable_cid: None,
+                dispute_cid: None,
+                candidate_share: 0,
+                updated_at: timestamp,
+            });
+        }
+        token::Client::new(&env, &token).transfer(&employer, &env.current_contract_address(), &total);
+        
+        let engagement_id = Self::get_next_engagement_id(&env);
+        let engagement = Engagement {
+            engagement_id,
+            application_id,
+            employer,
+            candidate: application.candidate,
+            arbitrator,
+            token,
+            milestones,
+            status: EngagementStatus::Active,
+            created_at: timestamp,
+        };
+        
+        env.storage().persistent().set(&(symbol_short!("gig"), engagement_id), &engagement);
+        env.storage().persistent().set(&symbol_short!("gig_cnt"), &(engagement_id + 1));
+        env.storage().persistent().set(&engagement_key, &engagement_id);
+        
+        env.events().publish((GIG, symbol_short!("start"), application_id), (engagement_id, total));
+        Ok(engagement)
+    }
+    
+    /// Candidate submits (or resubmits) the deliverable for a milestone
+    pub fn submit_deliverable(
+        env: Env,
+        candidate: Address,
+        engagement_id: u64,
+        milestone: u32,
+        deliverable_cid: String,
+    ) -> Result<Engagement, Error> {
+        candidate.require_auth();
+        
+        let mut engagement = Self::load_engagement(&env, engagement_id)?;
+        if engagement.candidate != candidate {
+            return Err(Error::NotAuthorized);
+        }
+        let mut entry = Self::load_milestone(&engagement, milestone)?;
+        if !matches!(entry.status, MilestoneStatus::Funded | MilestoneStatus::Submitted) {
+            return Err(Error::InvalidTransition);
+        }
+        
+        entry.deliverable_cid = Some(deliverable_cid);
+        entry.status = MilestoneStatus::Submitted;
+        Self::save_milestone(&env, symbol_short!("submit"), &mut engagement, milestone, entry);
+        Ok(engagement)
+    }
+    
+    /// Employer (or organization recruiter) approves a submitted deliverable, releasing the milestone to the candidate
+    pub fn approve_milestone(
+        env: Env,
+        employer: Address,
+        engagement_id: u64,
+        milestone: u32,
+    ) -> Result<Engagement, Error> {
+        employer.require_auth();
+        
+        let mut engagement = Self::load_engagement(&env, engagement_id)?;
+        Self::load_for_reviewer(&env, &employer, engagement.application_id)?;
+        let mut entry = Self::load_milestone(&engagement, milestone)?;
+        if entry.status != MilestoneStatus::Submitted {
+            return Err(Error::InvalidTransition);
+        }
+        
+        token::Client::new(&env, &engagement.token).transfer(
+            &env.current_contract_address(),
+            &engagement.candidate,
+            &entry.amount,
+        );
+        entry.candidate_share = entry.amount;
+        entry.status = MilestoneStatus::Released;
+        Self::save_milestone(&env, symbol_short!("release"), &mut engagement, milestone, entry);
+        Ok(engagement)
+    }
+    
+    /// Either party disputes an unsettled milestone, locking its funds for the arbitrator
+    pub fn raise_dispute(
+        env: Env,
+        party: Address,
+        engagement_id: u64,
+        milestone: u32,
+        reason_cid: String,
+    ) -> Result<Engagement, Error> {
+        party.require_auth();
+        
+        let mut engagement = Self::load_engagement(&env, engagement_id)?;
+        if party != engagement.candidate {
+            Self::load_for_reviewer(&env, &party, engagement.application_id)?;
+        }
+        let mut entry = Self::load_milestone(&engagement, milestone)?;
+        if !matches!(entry.status, MilestoneStatus::Funded | MilestoneStatus::Submitted) {
+            return Err(Error::InvalidTransition);
+        }
+        
+        entry.dispute_cid = Some(reason_cid);
+        entry.status = MilestoneStatus::Disputed;
+        Self::save_milestone(&env, symbol_short!("dispute"), &mut engagement, milestone, entry);
+        Ok(engagement)
+    }
+    
+    /// Arbitrator settles a disputed milestone; the candidate gets `candidate_share`, the employer who funded it the rest
+    pub fn resolve_dispute(
+        env: Env,
+        arbitrator: Address,
+        engagement_id: u64,
+        milestone: u32,
+        candidate_share: i128,
+    ) -> Result<Engagement, Error> {
+        arbitrator.require_auth();
+        
+        let mut engagement = Self::load_engagement(&env, engagement_id)?;
+        if engagement.arbitrator != arbitrator {
+            return Err(Error::NotAuthorized);
+        }
+        let mut entry = Self::load_milestone(&engagement, milestone)?;
+        if entry.status != MilestoneStatus::Disputed {
+            return Err(Error::InvalidTransition);
+        }
+        if candidate_share < 0 || candidate_share > entry.amount {
+            return Err(Error::InvalidDeposit);
+        }
+        
+        let client = token::Client::new(&env, &engagement.token);
+        if candidate_share > 0 {
+            client.transfer(&env.current_contract_address(), &engagement.candidate, &candidate_share);
+        }
+        if candidate_share < entry.amount {
+            client.transfer(&env.current_contract_address(), &engagement.employer, &(entry.amount - candidate_share));
+        }
+        entry.candidate_share = candidate_share;
+        entry.status = MilestoneStatus::Resolved;
+        Self::save_milestone(&env, symbol_short!("resolve"), &mut engagement, milestone, entry);
+        Ok(engagement)
+    }
+    
+    /// Get engagement details
+    pub fn get_engagement(env: Env, engagement_id: u64) -> Option<Engagement> {
+        env.storage().persistent().get(&(symbol_short!("gig"), engagement_id))
+    }
+    
+    /// Get the engagement started from an application
+    pub fn get_application_engagement(env: Env, application_id: u64) -> Option<Engagement> {
+        let engagement_id: u64 = env.storage().persistent().get(&(symbol_short!("app_gig"), application_id))?;
+        Self::get_engagement(env, engagement_id)
+    }
+    
+    // Helper functions
+    fn load_engagement(env: &Env, engagement_id: u64) -> Result<Engagement, Error> {
+        Self::get_engagement(env.clone(), engagement_id).ok_or(Error::EngagementNotFound)
+    }
+    
+    fn load_milestone(engagement: &Engagement, milestone: u32) -> Result<Milestone, Error> {
+        engagement.milestones.get(milestone).ok_or(Error::MilestoneNotFound)
+    }
+    
+    fn save_milestone(env: &Env, action: Symbol, engagement: &mut Engagement, milestone: u32, mut entry: Milestone) {
+        entry.updated_at = env.ledger().timestamp();
+        engagement.milestones.set(milestone, entry);
+        
+        let settled = engagement.milestones.iter()
+            .all(|entry| matches!(entry.status, MilestoneStatus::Released | MilestoneStatus::Resolved));
+        if settled {
+            engagement.status = EngagementStatus::Completed;
+        }
+        
+        env.storage().persistent().set(&(symbol_short!("gig"), engagement.engagement_id), engagement);
+        env.events().publish((GIG, action, engagement.application_id), (engagement.engagement_id, milestone));
+    }
+    
+    fn get_next_engagement_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("gig_cnt"))
+            .unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compensation, Currency, PayPeriod};
+    use soroban_sdk::testutils::Address as _;
+    
+    #[test]
+    fn test_milestone_escrow() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let arbitrator = Address::generate(&env);
+        let spec = String::from_str(&env, "QmSpec");
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&employer, &1_000);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Logo design"),
+            &String::from_str(&env, "QmGig"),
+            &Compensation {
+                min: 500,
+                max: 800,
+                currency: Currency::Asset(asset.address()),
+                period: PayPeriod::Monthly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        
+        let terms = Vec::from_array(&env, [
+            MilestoneTerms { description_cid: spec.clone(), amount: 300 },
+            MilestoneTerms { description_cid: spec.clone(), amount: 500 },
+        ]);
+        assert_eq!(
+            client.try_start_engagement(&employer, &1, &asset.address(), &arbitrator, &terms).err(),
+            Some(Ok(Error::InvalidTransition))
+        );
+        client.review_application(&employer, &1);
+        client.accept_application(&employer, &1);
+        assert_eq!(
+            client.try_start_engagement(&employer, &1, &asset.address(), &candidate, &terms).err(),
+            Some(Ok(Error::InvalidArbitrator))
+        );
+        client.start_engagement(&employer, &1, &asset.address(), &arbitrator, &terms);
+        assert_eq!(token.balance(&contract_id), 800);
+        assert_eq!(client.get_application_engagement(&1).unwrap().engagement_id, 1);
+        
+        // Milestone 0: delivered and approved
+        assert_eq!(client.try_approve_milestone(&employer, &1, &0).err(), Some(Ok(Error::InvalidTransition)));
+        client.submit_deliverable(&candidate, &1, &0, &String::from_str(&env, "QmLogoV1"));
+        assert_eq!(client.try_approve_milestone(&candidate, &1, &0).err(), Some(Ok(Error::NotAuthorized)));
+        client.approve_milestone(&employer, &1, &0);
+        assert_eq!(token.balance(&candidate), 300);
+        
+        // Milestone 1: disputed, funds locked until the arbitrator splits them
+        client.submit_deliverable(&candidate, &1, &1, &String::from_str(&env, "QmLogoV2"));
+        client.raise_dispute(&employer, &1, &1, &String::from_str(&env, "QmNotAsAgreed"));
+        assert_eq!(client.try_approve_milestone(&employer, &1, &1).err(), Some(Ok(Error::InvalidTransition)));
+        assert_eq!(client.try_resolve_dispute(&employer, &1, &1, &0).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_resolve_dispute(&arbitrator, &1, &1, &600).err(), Some(Ok(Error::InvalidDeposit)));
+        assert_eq!(client.try_resolve_dispute(&arbitrator, &1, &2, &0).err(), Some(Ok(Error::MilestoneNotFound)));
+        
+        let engagement = client.resolve_dispute(&arbitrator, &1, &1, &200);
+        assert_eq!(engagement.status, EngagementStatus::Completed);
+        assert_eq!(engagement.milestones.get(1).unwrap().candidate_share, 200);
+        assert_eq!(token.balance(&candidate), 500);
+        assert_eq!(token.balance(&employer), 500);
+        assert_eq!(token.balance(&contract_id), 0);
+    }
+}
@@ -0,0 +1,496 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::{Application, Error, JobApplicationContract, JobApplicationContractClient};
+
+// Event topic for interview scheduling changes
+const INTERVIEW: Symbol = symbol_short!("interview");
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterviewStatus {
+    Proposed,       // Waiting for the other party to pick a slot
+    Scheduled,
+    Declined,
+    Cancelled,
+    Completed,
+    NoShow,
+    Contested,      // The party marked absent disputes the no-show
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterviewOutcome {
+    Pending,
+    Passed,
+    Failed,
+    OnHold,
+}
+
+// One interview round for an application
+#[contracttype]
+#[derive(Clone)]
+pub struct Interview {
+    pub interview_id: u64,
+    pub application_id: u64,
+    pub round: u32,                 // 1 for the first interview of an application
+    pub employer: Address,
+    pub candidate: Address,
+    pub proposed_by: Address,       // The other party picks one of `slots`
+    pub slots: Vec<u64>,            // Candidate start times
+    pub scheduled_at: Option<u64>,  // Chosen slot once scheduled
+    pub details_cid: String,        // Location, link and panel on IPFS
+    pub status: InterviewStatus,
+    pub outcome: InterviewOutcome,
+    pub outcome_cid: Option<String>, // Interviewer feedback on IPFS
+    pub no_show: Option<Address>,   // Party that missed the interview
+    pub no_show_marked_by: Option<Address>, // Who recorded the no-show
+    pub contest_cid: Option<String>, // Evidence from the party marked absent, on IPFS
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+// One page of a cursor-paginated interview listing
+#[contracttype]
+#[derive(Clone)]
+pub struct InterviewPage {
+    pub interviews: Vec<Interview>,
+    pub next_cursor: Option<u64>,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer (or organization recruiter) proposes an interview round with one or more time slots
+    pub fn propose_interview(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+        slots: Vec<u64>,
+        details_cid: String,
+    ) -> Result<Interview, Error> {
+        employer.require_auth();
+        
+        let application: Application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if application.status.is_terminal() {
+            return Err(Error::InvalidTransition);
+        }
+        Self::check_slots(&env, &slots)?;
+        
+        let interview_id = Self::get_next_interview_id(&env);
+        let round = Self::get_index(&env, (symbol_short!("app_ints"), application_id)).len() + 1;
+        let timestamp = env.ledger().timestamp();
+        
+        let interview = Interview {
+            interview_id,
+            application_id,
+            round,
+            employer: employer.clone(),
+            candidate: application.candidate.clone(),
+            proposed_by: employer.clone(),
+            slots,
+            scheduled_at: None,
+            details_cid,
+            status: InterviewStatus::Proposed,
+            outcome: InterviewOutcome::Pending,
+            outcome_cid: None,
+            no_show: None,
+            no_show_marked_by: None,
+            contest_cid: None,
+            created_at: timestamp,
+            updated_at: timestamp,
+        };
+        
+        env.storage().persistent().set(&(symbol_short!("int"), interview_id), &interview);
+        env.storage().persistent().set(&symbol_short!("int_cnt"), &(interview_id + 1));
+        
+        Self::push_index(&env, (symbol_short!("app_ints"), application_id), interview_id);
+        Self::push_index(&env, (symbol_short!("prt_ints"), employer), interview_id);
+        Self::push_index(&env, (symbol_short!("prt_ints"), application.candidate), interview_id);
+        
+        Self::publish_interview(&env, symbol_short!("propose"), &interview);
+        Ok(interview)
+    }
+    
+    /// The party that did not propose the slots picks one
+    pub fn confirm_interview_slot(
+        env: Env,
+        participant: Address,
+        interview_id: u64,
+        slot: u64,
+    ) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        let own_proposal = Self::same_side(&interview, &interview.proposed_by, &participant);
+        if interview.status != InterviewStatus::Proposed || own_proposal {
+            return Err(Error::InvalidTransition);
+        }
+        if !interview.slots.contains(slot) || slot < env.ledger().timestamp() {
+            return Err(Error::InvalidSlot);
+        }
+        
+        interview.scheduled_at = Some(slot);
+        interview.status = InterviewStatus::Scheduled;
+        Self::save_interview(&env, symbol_short!("schedule"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// The party that did not propose the slots turns all of them down
+    pub fn decline_interview(env: Env, participant: Address, interview_id: u64) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        let own_proposal = Self::same_side(&interview, &interview.proposed_by, &participant);
+        if interview.status != InterviewStatus::Proposed || own_proposal {
+            return Err(Error::InvalidTransition);
+        }
+        
+        interview.status = InterviewStatus::Declined;
+        Self::save_interview(&env, symbol_short!("decline"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// Either party proposes new slots for a pending or scheduled round
+    pub fn reschedule_interview(
+        env: Env,
+        participant: Address,
+        interview_id: u64,
+        slots: Vec<u64>,
+    ) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        if !matches!(interview.status, InterviewStatus::Proposed | InterviewStatus::Scheduled) {
+            return Err(Error::InvalidTransition);
+        }
+        Self::check_slots(&env, &slots)?;
+        
+        interview.slots = slots;
+        interview.proposed_by = participant;
+        interview.scheduled_at = None;
+        interview.status = InterviewStatus::Proposed;
+        Self::save_interview(&env, symbol_short!("resched"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// Either party cancels a pending or scheduled round
+    pub fn cancel_interview(env: Env, participant: Address, interview_id: u64) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        if !matches!(interview.status, InterviewStatus::Proposed | InterviewStatus::Scheduled) {
+            return Err(Error::InvalidTransition);
+        }
+        
+        interview.status = InterviewStatus::Cancelled;
+        Self::save_interview(&env, symbol_short!("cancel"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// Either party records that the other did not turn up, once the scheduled start has passed
+    pub fn mark_no_show(env: Env, participant: Address, interview_id: u64) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        Self::require_started(&env, &interview)?;
+        if interview.scheduled_at.is_some_and(|start| env.ledger().timestamp() <= start) {
+            return Err(Error::InterviewNotStarted);
+        }
+        
+        interview.no_show = Some(if participant == interview.candidate {
+            interview.employer.clone()
+        } else {
+            interview.candidate.clone()
+        });
+        interview.no_show_marked_by = Some(participant);
+        interview.status = InterviewStatus::NoShow;
+        Self::save_interview(&env, symbol_short!("noshow"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// The party marked absent disputes the no-show with evidence
+    pub fn contest_no_show(
+        env: Env,
+        participant: Address,
+        interview_id: u64,
+        evidence_cid: String,
+    ) -> Result<Interview, Error> {
+        participant.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &participant, interview_id)?;
+        let marked_by = match (&interview.status, &interview.no_show_marked_by) {
+            (InterviewStatus::NoShow, Some(marked_by)) => marked_by.clone(),
+            _ => return Err(Error::InvalidTransition),
+        };
+        if Self::same_side(&interview, &marked_by, &participant) {
+            return Err(Error::NotAuthorized);
+        }
+        
+        interview.contest_cid = Some(evidence_cid);
+        interview.status = InterviewStatus::Contested;
+        Self::save_interview(&env, symbol_short!("contest"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// Employer (or organization recruiter) records the result of a round once it has taken place
+    pub fn record_interview_outcome(
+        env: Env,
+        employer: Address,
+        interview_id: u64,
+        outcome: InterviewOutcome,
+        outcome_cid: String,
+    ) -> Result<Interview, Error> {
+        employer.require_auth();
+        
+        let mut interview = Self::load_for_participant(&env, &employer, interview_id)?;
+        if employer == interview.candidate {
+            return Err(Error::NotAuthorized);
+        }
+        if outcome == InterviewOutcome::Pending {
+            return Err(Error::InvalidTransition);
+        }
+        Self::require_started(&env, &interview)?;
+        
+        interview.outcome = outcome;
+        interview.outcome_cid = Some(outcome_cid);
+        interview.status = InterviewStatus::Completed;
+        Self::save_interview(&env, symbol_short!("outcome"), &mut interview);
+        Ok(interview)
+    }
+    
+    /// Get interview details
+    pub fn get_interview(env: Env, interview_id: u64) -> Option<Interview> {
+        env.storage().persistent().get(&(symbol_short!("int"), interview_id))
+    }
+    
+    /// Get every interview round of an application, in round order
+    pub fn get_application_interviews(env: Env, application_id: u64) -> Vec<Interview> {
+        let mut interviews = Vec::new(&env);
+        for interview_id in Self::get_index(&env, (symbol_short!("app_ints"), application_id)).iter() {
+            if let Some(interview) = Self::get_interview(env.clone(), interview_id) {
+                interviews.push_back(interview);
+            }
+        }
+        interviews
+    }
+    
+    /// List interviews an employer or candidate takes part in, oldest first
+    pub fn get_participant_interviews(
+        env: Env,
+        participant: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> InterviewPage {
+        let ids = Self::get_index(&env, (symbol_short!("prt_ints"), participant));
+        let (interviews, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |interview_id| {
+            Self::get_interview(env.clone(), interview_id)
+        });
+        InterviewPage { interviews, next_cursor }
+    }
+    
+    // Helper functions
+    fn check_slots(env: &Env, slots: &Vec<u64>) -> Result<(), Error> {
+        let now = env.ledger().timestamp();
+        if slots.is_empty() || slots.iter().any(|slot| slot < now) {
+            return Err(Error::InvalidSlot);
+        }
+        Ok(())
+    }
+    
+    // The candidate, or anyone who may manage the job (the employer or a current organization recruiter)
+    fn load_for_participant(env: &Env, participant: &Address, interview_id: u64) -> Result<Interview, Error> {
+        let interview = Self::get_interview(env.clone(), interview_id).ok_or(Error::InterviewNotFound)?;
+        if interview.candidate != *participant {
+            Self::load_for_reviewer(env, participant, interview.application_id)?;
+        }
+        Ok(interview)
+    }
+    
+    // Whether two participants act for the same side of the interview
+    fn same_side(interview: &Interview, first: &Address, second: &Address) -> bool {
+        (*first == interview.candidate) == (*second == interview.candidate)
+    }
+    
+    fn require_started(env: &Env, interview: &Interview) -> Result<(), Error> {
+        if interview.status != InterviewStatus::Scheduled {
+            return Err(Error::InvalidTransition);
+        }
+        if interview.scheduled_at.is_some_and(|start| env.ledger().timestamp() < start) {
+            return Err(Error::InterviewNotStarted);
+        }
+        Ok(())
+    }
+    
+    fn save_interview(env: &Env, action: Symbol, interview: &mut Interview) {
+        interview.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&(symbol_short!("int"), interview.interview_id), interview);
+        Self::publish_interview(env, action, interview);
+    }
+    
+    fn get_next_interview_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("int_cnt"))
+            .unwrap_or(1)
+    }
+    
+    fn publish_interview(env: &Env, action: Symbol, interview: &Interview) {
+        env.events().publish(
+            (INTERVIEW, action, interview.application_id),
+            (interview.interview_id, interview.status.clone()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compensation, Currency, OrgRole, PayPeriod};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    
+    #[test]
+    fn test_interview_scheduling() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let details = String::from_str(&env, "QmInterview");
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 80_000,
+                max: 80_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        
+        assert_eq!(
+            client.try_propose_interview(&employer, &1, &Vec::from_array(&env, [500]), &details).err(),
+            Some(Ok(Error::InvalidSlot))
+        );
+        assert_eq!(
+            client.try_propose_interview(&outsider, &1, &Vec::from_array(&env, [2_000]), &details).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        
+        // Round 1: candidate picks a slot, the employer moves it, the candidate confirms again
+        let first = client.propose_interview(&employer, &1, &Vec::from_array(&env, [2_000, 3_000]), &details);
+        assert_eq!(first.round, 1);
+        assert_eq!(client.try_confirm_interview_slot(&employer, &1, &2_000).err(), Some(Ok(Error::InvalidTransition)));
+        assert_eq!(client.try_confirm_interview_slot(&candidate, &1, &2_500).err(), Some(Ok(Error::InvalidSlot)));
+        assert_eq!(client.confirm_interview_slot(&candidate, &1, &2_000).scheduled_at, Some(2_000));
+        
+        client.reschedule_interview(&employer, &1, &Vec::from_array(&env, [4_000]));
+        let scheduled = client.confirm_interview_slot(&candidate, &1, &4_000);
+        assert_eq!(scheduled.status, InterviewStatus::Scheduled);
+        
+        let passed = InterviewOutcome::Passed;
+        let feedback = String::from_str(&env, "QmFeedback");
+        assert_eq!(client.try_record_interview_outcome(&employer, &1, &passed, &feedback).err(), Some(Ok(Error::InterviewNotStarted)));
+        env.ledger().with_mut(|ledger| ledger.timestamp = 4_000);
+        assert_eq!(client.try_record_interview_outcome(&candidate, &1, &passed, &feedback).err(), Some(Ok(Error::NotAuthorized)));
+        let completed = client.record_interview_outcome(&employer, &1, &passed, &feedback);
+        assert_eq!(completed.status, InterviewStatus::Completed);
+        assert_eq!(completed.outcome, InterviewOutcome::Passed);
+        
+        // Round 2: the candidate counter-proposes, is marked absent once the slot has passed, and contests it
+        client.propose_interview(&employer, &1, &Vec::from_array(&env, [5_000]), &details);
+        client.reschedule_interview(&candidate, &2, &Vec::from_array(&env, [6_000]));
+        assert_eq!(client.try_confirm_interview_slot(&candidate, &2, &6_000).err(), Some(Ok(Error::InvalidTransition)));
+        client.confirm_interview_slot(&employer, &2, &6_000);
+        env.ledger().with_mut(|ledger| ledger.timestamp = 6_000);
+        assert_eq!(client.try_mark_no_show(&employer, &2).err(), Some(Ok(Error::InterviewNotStarted)));
+        env.ledger().with_mut(|ledger| ledger.timestamp = 6_100);
+        let missed = client.mark_no_show(&employer, &2);
+        assert_eq!(missed.status, InterviewStatus::NoShow);
+        assert_eq!(missed.no_show, Some(candidate.clone()));
+        assert_eq!(missed.no_show_marked_by, Some(employer.clone()));
+        
+        let evidence = String::from_str(&env, "QmCallLog");
+        assert_eq!(client.try_contest_no_show(&employer, &2, &evidence).err(), Some(Ok(Error::NotAuthorized)));
+        let contested = client.contest_no_show(&candidate, &2, &evidence);
+        assert_eq!(contested.status, InterviewStatus::Contested);
+        assert_eq!(contested.contest_cid, Some(evidence.clone()));
+        assert_eq!(client.try_contest_no_show(&candidate, &2, &evidence).err(), Some(Ok(Error::InvalidTransition)));
+        
+        // Round 3 is declined, round 4 cancelled
+        client.propose_interview(&employer, &1, &Vec::from_array(&env, [7_000]), &details);
+        client.decline_interview(&candidate, &3);
+        client.propose_interview(&employer, &1, &Vec::from_array(&env, [8_000]), &details);
+        client.cancel_interview(&candidate, &4);
+        assert_eq!(client.try_cancel_interview(&employer, &4).err(), Some(Ok(Error::InvalidTransition)));
+        
+        let rounds = client.get_application_interviews(&1);
+        assert_eq!(rounds.len(), 4);
+        assert_eq!(rounds.get(3).unwrap().round, 4);
+        assert_eq!(rounds.get(2).unwrap().status, InterviewStatus::Declined);
+        assert_eq!(client.get_participant_interviews(&candidate, &0, &10).interviews.len(), 4);
+        let page = client.get_participant_interviews(&employer, &0, &3);
+        assert_eq!(page.interviews.len(), 3);
+        assert_eq!(page.next_cursor, Some(3));
+        assert_eq!(client.get_participant_interviews(&outsider, &0, &10).interviews.len(), 0);
+    }
+    
+    #[test]
+    fn test_organization_recruiters_run_interviews() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let recruiter = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let details = String::from_str(&env, "QmInterview");
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &recruiter, &OrgRole::Recruiter);
+        client.post_organization_job(
+            &owner,
+            &1,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 80_000,
+                max: 80_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        
+        // A recruiter proposes; the owner is on the same side, so only the candidate confirms
+        client.propose_interview(&recruiter, &1, &Vec::from_array(&env, [2_000]), &details);
+        assert_eq!(client.try_confirm_interview_slot(&owner, &1, &2_000).err(), Some(Ok(Error::InvalidTransition)));
+        client.confirm_interview_slot(&candidate, &1, &2_000);
+        env.ledger().with_mut(|ledger| ledger.timestamp = 2_100);
+        client.record_interview_outcome(&owner, &1, &InterviewOutcome::Passed, &String::from_str(&env, "QmFeedback"));
+        
+        // Removed recruiters lose the employer side
+        client.remove_member(&owner, &1, &recruiter);
+        assert_eq!(
+            client.try_propose_interview(&recruiter, &1, &Vec::from_array(&env, [3_000]), &details).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+    }
+}
@@ -1,14 +1,205 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, symbol_short};
+mod committees;
+mod gigs;
+mod interviews;
+mod offers;
+mod organizations;
+mod ratings;
+mod stages;
+
+pub use committees::{CommitteeVote, HiringCommittee, Vote};
+pub use gigs::{Engagement, EngagementStatus, Milestone, MilestoneStatus, MilestoneTerms};
+pub use interviews::{Interview, InterviewOutcome, InterviewPage, InterviewStatus};
+pub use offers::{CounterOffer, Offer, OfferStatus};
+pub use organizations::{OrgRole, Organization};
+pub use ratings::{Rating, Reputation};
+
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, token, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec, symbol_short};
+
+// Upper bound on items returned by a single paginated query
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Upper bound on index entries a single paginated query examines, matching or not
+const MAX_PAGE_SCAN: u32 = 100;
+
+// Event topics (listed in the README)
+const JOB: Symbol = symbol_short!("job");              // Postings and their escrows
+const APPLICATION: Symbol = symbol_short!("app");      // Application lifecycle
+const CONFIG: Symbol = symbol_short!("config");        // Admin wiring
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    JobNotFound = 1,
+    ApplicationNotFound = 2,
+    NotAuthorized = 3,
+    JobInactive = 4,
+    InvalidTransition = 5,
+    AlreadyApplied = 6,
+    ReapplyCooldown = 7,
+    DeadlinePassed = 8,
+    InvalidCompensation = 9,
+    InvalidDeposit = 10,
+    EscrowNotFound = 11,
+    SlaNotBreached = 12,
+    AlreadyClaimed = 13,
+    SelfReferral = 14,
+    AlreadyInitialized = 15,
+    NotInitialized = 16,
+    ProfileRequired = 17,
+    InsufficientVerifications = 18,
+    MissingVerifiedDocType = 19,
+    InterviewNotFound = 20,
+    InvalidSlot = 21,
+    InterviewNotStarted = 22,
+    OfferNotFound = 23,
+    OfferExpired = 24,
+    InvalidExpiry = 25,
+    EngagementNotFound = 26,
+    MilestoneNotFound = 27,
+    InvalidArbitrator = 28,
+    InvalidScore = 29,
+    AlreadyRated = 30,
+    OrganizationNotFound = 31,
+    CommitteeNotFound = 32,
+    InvalidQuorum = 33,
+    AlreadyVoted = 34,
+    QuorumNotMet = 35,
+    InvalidStage = 36,
+    PipelineIncomplete = 37,
+    SelfApplication = 38,
+    CommitteeLocked = 39,
+}
 
 #[contracttype]
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ApplicationStatus {
     Pending,
     UnderReview,
     Verified,
     Rejected,
     Accepted,
+    Withdrawn,
+    PositionClosed,
+    Hired,          // Candidate countersigned an offer
+}
+
+impl ApplicationStatus {
+    /// Terminal outcomes; employer decisions can only be left through `reopen_application`
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ApplicationStatus::Rejected
+                | ApplicationStatus::Accepted
+                | ApplicationStatus::Withdrawn
+                | ApplicationStatus::PositionClosed
+                | ApplicationStatus::Hired
+        )
+    }
+    
+    /// Allowed forward moves in the review pipeline
+    pub fn can_transition_to(&self, next: &ApplicationStatus) -> bool {
+        use ApplicationStatus::*;
+        matches!(
+            (self, next),
+            (Pending, UnderReview)
+                | (Pending, Verified)
+                | (Pending, Rejected)
+                | (UnderReview, Verified)
+                | (UnderReview, Rejected)
+                | (UnderReview, Accepted)
+                | (Verified, Accepted)
+                | (Verified, Rejected)
+        )
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PayPeriod {
+    Hourly,
+    Monthly,
+    Yearly,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Currency {
+    Asset(Address),     // Stellar asset contract
+    Iso(String),        // ISO 4217 code, e.g. "USD"
+}
+
+// Typed pay band; amounts are in the currency's smallest unit
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Compensation {
+    pub min: i128,
+    pub max: i128,
+    pub currency: Currency,
+    pub period: PayPeriod,
+    pub equity: bool,
+}
+
+impl Compensation {
+    pub fn is_valid(&self) -> bool {
+        self.min >= 0 && self.min <= self.max
+    }
+    
+    /// Same currency and period, and the two ranges share at least one amount
+    pub fn overlaps(&self, other: &Compensation) -> bool {
+        self.currency == other.currency
+            && self.period == other.period
+            && self.min <= other.max
+            && other.min <= self.max
+    }
+}
+
+// Token amount held in escrow by this contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deposit {
+    pub token: Address,     // Soroban token contract
+    pub amount: i128,
+}
+
+// Referral bounty escrowed for a job; an unpaid bounty goes back to whoever funded it
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferralBounty {
+    pub funder: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+// Employer stake backing a review SLA; ghosted candidates claim `payout` each
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseStake {
+    pub funder: Address,    // Who staked; top-ups must come from them and the remainder returns to them
+    pub token: Address,
+    pub balance: i128,
+    pub sla: u64,           // Seconds an application may stay Pending; fixed at the first stake
+    pub payout: i128,       // Paid per application that breaches the SLA; fixed at the first stake
+}
+
+// On-chain checks an applicant must pass; enforced in apply_to_job
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobRequirements {
+    pub require_profile: bool,          // Registered in candidate-profile
+    pub min_verifications: u32,         // Active records in document-verification
+    pub required_doc_types: Vec<String>, // Each needs a current verified document in candidate-profile
+}
+
+// One entry in an application's audit trail
+#[contracttype]
+#[derive(Clone)]
+pub struct StatusChange {
+    pub status: ApplicationStatus,
+    pub actor: Address,
+    pub timestamp: u64,
+    pub note: Option<String>,
 }
 
 // Job posting on-chain
@@ -19,10 +210,26 @@ pub struct JobPosting {
     pub employer: Address,
     pub title: String,
     pub ipfs_details: String,     // Full job description on IPFS
-    pub salary_range: String,
+    pub compensation: Compensation,
     pub is_active: bool,
     pub created_at: u64,
     pub application_count: u32,
+    pub active_application_count: u32,  // Applications not yet in a terminal state
+    pub reapply_cooldown: Option<u64>,  // Seconds after rejection/withdrawal before re-applying; None = never
+    pub deadline: Option<u64>,          // Last timestamp at which applications are accepted
+    pub max_applications: Option<u32>,  // Job closes once this many applications are received
+    pub revision: u32,                  // 1 at posting, bumped by every update_job
+    pub organization: Option<u64>,      // Owning organization; its members can review and close
+    pub stages: Vec<String>,            // Custom pipeline stages in order; empty = built-in statuses only
+}
+
+// Superseded version of a job posting
+#[contracttype]
+#[derive(Clone)]
+pub struct JobRevision {
+    pub revision: u32,                  // Revision introduced by the update
+    pub previous_ipfs_details: String,  // Description CID before the update
+    pub timestamp: u64,
 }
 
 // Job application linking candidate to job
@@ -34,11 +241,44 @@ pub struct Application {
     pub candidate: Address,
     pub employer: Address,
     pub status: ApplicationStatus,
-    pub candidate_profile_cid: String,  // Link to candidate profile
+    pub candidate_profile_cid: String,  // Snapshot from candidate-profile at apply time; empty if none
     pub cover_letter_cid: String,       // IPFS
     pub applied_at: u64,
     pub reviewed_at: Option<u64>,
+    pub first_response_at: Option<u64>, // When the application first left Pending; never overwritten
     pub verification_notes: Option<String>,
+    pub job_revision: u32,              // Job revision the candidate applied against
+    pub referrer: Option<Address>,      // Earns the job's referral bounty on hire
+    pub stage: Option<u32>,             // Index into the job's stages; None if the job defines none
+}
+
+// One page of a cursor-paginated job listing
+#[contracttype]
+#[derive(Clone)]
+pub struct JobPage {
+    pub jobs: Vec<JobPosting>,
+    pub next_cursor: Option<u64>,   // Pass back to fetch the next page; None when exhausted
+}
+
+// One page of a cursor-paginated application listing
+#[contracttype]
+#[derive(Clone)]
+pub struct ApplicationPage {
+    pub applications: Vec<Application>,
+    pub next_cursor: Option<u64>,
+}
+
+// Interface of the deployed candidate-profile contract
+#[contractclient(name = "CandidateProfileClient")]
+pub trait CandidateProfile {
+    fn get_profile_cid(env: Env, candidate: Address) -> Option<String>;
+    fn has_verified_document_type(env: Env, candidate: Address, doc_type: String) -> bool;
+}
+
+// Interface of the deployed document-verification contract
+#[contractclient(name = "DocumentVerificationClient")]
+pub trait DocumentVerification {
+    fn get_verification_count(env: Env, candidate: Address) -> u32;
 }
 
 #[contract]
@@ -47,33 +287,51 @@ pub struct JobApplicationContract;
 #[contractimpl]
 impl JobApplicationContract {
     
-    /// Employer posts a job
+    /// One-time setup of the contract admin and the contracts applicants are checked against
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        candidate_profile: Address,
+        document_verification: Address,
+    ) -> Result<(), Error> {
+        if env.storage().persistent().has(&symbol_short!("admin")) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().persistent().set(&symbol_short!("admin"), &admin);
+        env.storage().persistent().set(&symbol_short!("profiles"), &candidate_profile);
+        env.storage().persistent().set(&symbol_short!("doc_verif"), &document_verification);
+        env.events().publish((CONFIG, symbol_short!("init")), (admin, candidate_profile, document_verification));
+        Ok(())
+    }
+    
+    /// Admin points the contract at different candidate-profile and document-verification deployments
+    pub fn set_verification_contracts(
+        env: Env,
+        candidate_profile: Address,
+        document_verification: Address,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().persistent().set(&symbol_short!("profiles"), &candidate_profile);
+        env.storage().persistent().set(&symbol_short!("doc_verif"), &document_verification);
+        env.events().publish((CONFIG, symbol_short!("contracts")), (candidate_profile, document_verification));
+        Ok(())
+    }
+    
+    /// Employer posts a job, optionally with its own ordered pipeline stages
+    #[allow(clippy::too_many_arguments)]
     pub fn post_job(
         env: Env,
         employer: Address,
         title: String,
         ipfs_details: String,
-        salary_range: String,
-    ) -> JobPosting {
+        compensation: Compensation,
+        deadline: Option<u64>,
+        max_applications: Option<u32>,
+        stages: Vec<String>,
+    ) -> Result<JobPosting, Error> {
         employer.require_auth();
-        
-        let job_id = Self::get_next_job_id(&env);
-        
-        let job = JobPosting {
-            job_id,
-            employer: employer.clone(),
-            title,
-            ipfs_details,
-            salary_range,
-            is_active: true,
-            created_at: env.ledger().timestamp(),
-            application_count: 0,
-        };
-        
-        env.storage().persistent().set(&(symbol_short!("job"), job_id), &job);
-        env.storage().persistent().set(&symbol_short!("job_cnt"), &(job_id + 1));
-        
-        job
+        Self::create_job(&env, employer, None, title, ipfs_details, compensation, deadline, max_applications, stages)
     }
     
     /// Candidate applies to a job
@@ -81,21 +339,51 @@ impl JobApplicationContract {
         env: Env,
         candidate: Address,
         job_id: u64,
-        candidate_profile_cid: String,
         cover_letter_cid: String,
-    ) -> Application {
+        referrer: Option<Address>,
+    ) -> Result<Application, Error> {
         candidate.require_auth();
         
+        if referrer.as_ref() == Some(&candidate) {
+            return Err(Error::SelfReferral);
+        }
+        
         // Get job
         let job_key = (symbol_short!("job"), job_id);
         let mut job: JobPosting = env.storage().persistent()
             .get(&job_key)
-            .expect("Job not found");
+            .ok_or(Error::JobNotFound)?;
+        
+        if !job.is_active {
+            return Err(Error::JobInactive);
+        }
+        if Self::deadline_passed(&env, &job) {
+            return Err(Error::DeadlinePassed);
+        }
+        if Self::is_job_insider(&env, &candidate, &job) {
+            return Err(Error::SelfApplication);
+        }
         
-        assert!(job.is_active, "Job is not active");
+        let candidate_profile_cid = Self::check_requirements(&env, job_id, &candidate)?;
+        
+        // One live application per candidate and job
+        let candidate_key = (symbol_short!("job_cand"), job_id, candidate.clone());
+        if let Some(previous_id) = env.storage().persistent().get::<_, u64>(&candidate_key) {
+            Self::check_reapply(&env, &job, previous_id)?;
+        }
         
         let application_id = Self::get_next_application_id(&env);
         
+        // Anti-spam deposit is held by this contract until the application leaves Pending
+        if let Some(deposit) = Self::get_job_deposit(env.clone(), job_id) {
+            token::Client::new(&env, &deposit.token).transfer(
+                &candidate,
+                &env.current_contract_address(),
+                &deposit.amount,
+            );
+            env.storage().persistent().set(&(symbol_short!("app_dep"), application_id), &deposit);
+        }
+        
         let application = Application {
             application_id,
             job_id,
@@ -106,7 +394,11 @@ impl JobApplicationContract {
             cover_letter_cid,
             applied_at: env.ledger().timestamp(),
             reviewed_at: None,
+            first_response_at: None,
             verification_notes: None,
+            job_revision: job.revision,
+            referrer,
+            stage: if job.stages.is_empty() { None } else { Some(0) },
         };
         
         // Store application
@@ -116,217 +408,1864 @@ impl JobApplicationContract {
         );
         env.storage().persistent().set(&symbol_short!("app_cnt"), &(application_id + 1));
         
+        env.storage().persistent().set(&candidate_key, &application_id);
+        Self::push_index(&env, (symbol_short!("job_apps"), job_id), application_id);
+        Self::push_index(&env, (symbol_short!("cand_apps"), candidate.clone()), application_id);
+        Self::push_history(&env, application_id, &application.status, &candidate, None);
+        
         // Update job application count
         job.application_count += 1;
+        job.active_application_count += 1;
         env.storage().persistent().set(&job_key, &job);
         
-        application
+        env.events().publish(
+            (APPLICATION, symbol_short!("submit"), job_id, candidate),
+            application_id,
+        );
+        
+        // Reaching the applicant cap closes the job; submitted applications stay in review
+        if job.max_applications.is_some_and(|max| job.application_count >= max) {
+            Self::close(&env, job_id, false)?;
+        }
+        
+        Ok(application)
     }
     
-    /// Employer reviews application (marks as under review)
+    /// Employer or organization recruiter reviews application (marks as under review)
     pub fn review_application(
         env: Env,
         employer: Address,
         application_id: u64,
-    ) -> Application {
+    ) -> Result<Application, Error> {
         employer.require_auth();
         
-        let app_key = (symbol_short!("app"), application_id);
-        let mut application: Application = env.storage().persistent()
-            .get(&app_key)
-            .expect("Application not found");
-        
-        assert_eq!(application.employer, employer, "Not authorized");
-        
-        application.status = ApplicationStatus::UnderReview;
-        application.reviewed_at = Some(env.ledger().timestamp());
-        
-        env.storage().persistent().set(&app_key, &application);
-        application
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        Self::transition(&env, &mut application, ApplicationStatus::UnderReview, &employer, None)?;
+        Ok(application)
     }
     
-    /// Employer verifies candidate documents and marks application
+    /// Employer or organization recruiter verifies candidate documents and marks application
     pub fn verify_application(
         env: Env,
         employer: Address,
         application_id: u64,
         verification_notes: String,
-    ) -> Application {
+    ) -> Result<Application, Error> {
         employer.require_auth();
         
-        let app_key = (symbol_short!("app"), application_id);
-        let mut application: Application = env.storage().persistent()
-            .get(&app_key)
-            .expect("Application not found");
-        
-        assert_eq!(application.employer, employer, "Not authorized");
-        
-        application.status = ApplicationStatus::Verified;
-        application.verification_notes = Some(verification_notes);
-        application.reviewed_at = Some(env.ledger().timestamp());
-        
-        env.storage().persistent().set(&app_key, &application);
-        application
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        application.verification_notes = Some(verification_notes.clone());
+        Self::transition(&env, &mut application, ApplicationStatus::Verified, &employer, Some(verification_notes))?;
+        Ok(application)
     }
     
-    /// Employer rejects application
+    /// Employer or organization recruiter rejects application
     pub fn reject_application(
         env: Env,
         employer: Address,
         application_id: u64,
         reason: String,
-    ) -> Application {
+    ) -> Result<Application, Error> {
         employer.require_auth();
         
-        let app_key = (symbol_short!("app"), application_id);
-        let mut application: Application = env.storage().persistent()
-            .get(&app_key)
-            .expect("Application not found");
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        application.verification_notes = Some(reason.clone());
+        Self::transition(&env, &mut application, ApplicationStatus::Rejected, &employer, Some(reason))?;
+        Ok(application)
+    }
+    
+    /// Employer or organization recruiter accepts application
+    pub fn accept_application(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+    ) -> Result<Application, Error> {
+        employer.require_auth();
+        
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        Self::require_quorum(&env, &application)?;
+        Self::require_final_stage(&env, &application)?;
+        Self::transition(&env, &mut application, ApplicationStatus::Accepted, &employer, None)?;
+        Self::pay_referral_bounty(&env, &application);
+        Ok(application)
+    }
+    
+    /// Employer reopens a rejected or accepted application for another review
+    pub fn reopen_application(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+        note: String,
+    ) -> Result<Application, Error> {
+        employer.require_auth();
         
-        assert_eq!(application.employer, employer, "Not authorized");
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if !matches!(application.status, ApplicationStatus::Rejected | ApplicationStatus::Accepted) {
+            return Err(Error::InvalidTransition);
+        }
+        // An outstanding offer has to be withdrawn first
+        if Self::has_open_offer(&env, application_id) {
+            return Err(Error::InvalidTransition);
+        }
+        // A rated hire stays settled so it cannot be rated again
+        if Self::is_rated(&env, &application) {
+            return Err(Error::AlreadyRated);
+        }
+        Self::record_status(&env, &mut application, ApplicationStatus::UnderReview, &employer, Some(note));
+        Ok(application)
+    }
+    
+    /// Candidate withdraws their own application while it is still open
+    pub fn withdraw_application(
+        env: Env,
+        candidate: Address,
+        application_id: u64,
+    ) -> Result<Application, Error> {
+        candidate.require_auth();
+        
+        let mut application: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), application_id))
+            .ok_or(Error::ApplicationNotFound)?;
         
-        application.status = ApplicationStatus::Rejected;
-        application.verification_notes = Some(reason);
-        application.reviewed_at = Some(env.ledger().timestamp());
+        if application.candidate != candidate {
+            return Err(Error::NotAuthorized);
+        }
+        if application.status.is_terminal() {
+            return Err(Error::InvalidTransition);
+        }
         
-        env.storage().persistent().set(&app_key, &application);
-        application
+        Self::record_status(&env, &mut application, ApplicationStatus::Withdrawn, &candidate, None);
+        Ok(application)
     }
     
-    /// Employer accepts application
-    pub fn accept_application(
+    /// Employer flags a pending application as spam; its deposit goes to the job's treasury
+    pub fn flag_spam(
         env: Env,
         employer: Address,
         application_id: u64,
-    ) -> Application {
+    ) -> Result<Application, Error> {
         employer.require_auth();
         
-        let app_key = (symbol_short!("app"), application_id);
-        let mut application: Application = env.storage().persistent()
-            .get(&app_key)
-            .expect("Application not found");
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if application.status != ApplicationStatus::Pending {
+            return Err(Error::InvalidTransition);
+        }
         
-        assert_eq!(application.employer, employer, "Not authorized");
+        if let Some(deposit) = Self::take_deposit(&env, application_id) {
+            let job = Self::get_job(env.clone(), application.job_id).ok_or(Error::JobNotFound)?;
+            token::Client::new(&env, &deposit.token).transfer(
+                &env.current_contract_address(),
+                &Self::job_treasury(&env, &job),
+                &deposit.amount,
+            );
+            env.events().publish(
+                (APPLICATION, symbol_short!("forfeit"), application.job_id, application.candidate.clone()),
+                (application_id, deposit.amount),
+            );
+        }
+        
+        let note = String::from_str(&env, "spam");
+        application.verification_notes = Some(note.clone());
+        Self::record_status(&env, &mut application, ApplicationStatus::Rejected, &employer, Some(note));
+        Ok(application)
+    }
+    
+    /// Employer requires a token deposit with each new application; an amount of 0 removes it
+    pub fn set_application_deposit(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        employer.require_auth();
         
-        application.status = ApplicationStatus::Accepted;
-        application.reviewed_at = Some(env.ledger().timestamp());
+        Self::load_managed_job(&env, &employer, job_id)?;
+        if amount < 0 {
+            return Err(Error::InvalidDeposit);
+        }
         
-        env.storage().persistent().set(&app_key, &application);
-        application
+        let key = (symbol_short!("job_dep"), job_id);
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &Deposit { token: token.clone(), amount });
+        }
+        env.events().publish((JOB, symbol_short!("deposit"), employer), (job_id, token, amount));
+        Ok(())
     }
     
-    /// Get job details
-    pub fn get_job(env: Env, job_id: u64) -> Option<JobPosting> {
-        env.storage().persistent().get(&(symbol_short!("job"), job_id))
+    /// Get the deposit a job requires from applicants
+    pub fn get_job_deposit(env: Env, job_id: u64) -> Option<Deposit> {
+        env.storage().persistent().get(&(symbol_short!("job_dep"), job_id))
     }
     
-    /// Get application details
-    pub fn get_application(env: Env, application_id: u64) -> Option<Application> {
-        env.storage().persistent().get(&(symbol_short!("app"), application_id))
+    /// Get the deposit still held for an application
+    pub fn get_application_deposit(env: Env, application_id: u64) -> Option<Deposit> {
+        env.storage().persistent().get(&(symbol_short!("app_dep"), application_id))
     }
     
-    /// Close job posting
-    pub fn close_job(env: Env, employer: Address, job_id: u64) -> JobPosting {
+    /// Employer stakes tokens promising to move applications out of Pending within `sla` seconds; restaking
+    /// with the same token and terms tops up
+    pub fn stake_for_response(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        token: Address,
+        amount: i128,
+        sla: u64,
+        payout: i128,
+    ) -> Result<ResponseStake, Error> {
         employer.require_auth();
         
-        let job_key = (symbol_short!("job"), job_id);
-        let mut job: JobPosting = env.storage().persistent()
-            .get(&job_key)
-            .expect("Job not found");
+        let job = Self::load_managed_job(&env, &employer, job_id)?;
+        if !job.is_active {
+            return Err(Error::JobInactive);
+        }
+        if amount <= 0 || payout <= 0 {
+            return Err(Error::InvalidDeposit);
+        }
         
-        assert_eq!(job.employer, employer, "Not authorized");
+        let key = (symbol_short!("stake"), job_id);
+        let mut stake = match env.storage().persistent().get::<_, ResponseStake>(&key) {
+            Some(existing)
+                if existing.funder != employer || existing.token != token || existing.sla != sla || existing.payout != payout =>
+            {
+                return Err(Error::InvalidDeposit)
+            }
+            Some(existing) => existing,
+            None => ResponseStake { funder: employer.clone(), token: token.clone(), balance: 0, sla, payout },
+        };
         
-        job.is_active = false;
-        env.storage().persistent().set(&job_key, &job);
-        job
+        token::Client::new(&env, &token).transfer(&employer, &env.current_contract_address(), &amount);
+        stake.balance += amount;
+        env.storage().persistent().set(&key, &stake);
+        
+        env.events().publish((JOB, symbol_short!("stake"), employer), (job_id, amount));
+        Ok(stake)
     }
     
-    // Helper functions
-    fn get_next_job_id(env: &Env) -> u64 {
-        env.storage().persistent()
-            .get(&symbol_short!("job_cnt"))
-            .unwrap_or(1)
+    /// Candidate claims a payout for an application left Pending past the job's SLA
+    pub fn claim_response_payout(
+        env: Env,
+        candidate: Address,
+        application_id: u64,
+    ) -> Result<i128, Error> {
+        candidate.require_auth();
+        
+        let application = Self::get_application(env.clone(), application_id)
+            .ok_or(Error::ApplicationNotFound)?;
+        if application.candidate != candidate {
+            return Err(Error::NotAuthorized);
+        }
+        
+        let key = (symbol_short!("stake"), application.job_id);
+        let mut stake: ResponseStake = env.storage().persistent()
+            .get(&key)
+            .ok_or(Error::EscrowNotFound)?;
+        
+        let claim_key = (symbol_short!("stk_clm"), application_id);
+        if env.storage().persistent().has(&claim_key) {
+            return Err(Error::AlreadyClaimed);
+        }
+        
+        if !Self::breached_sla(&env, &application, &stake) {
+            return Err(Error::SlaNotBreached);
+        }
+        
+        let amount = stake.payout.min(stake.balance);
+        if amount == 0 {
+            return Err(Error::EscrowNotFound);
+        }
+        
+        token::Client::new(&env, &stake.token).transfer(&env.current_contract_address(), &candidate, &amount);
+        stake.balance -= amount;
+        env.storage().persistent().set(&key, &stake);
+        env.storage().persistent().set(&claim_key, &amount);
+        
+        env.events().publish(
+            (APPLICATION, symbol_short!("ghosted"), application.job_id, candidate),
+            (application_id, amount),
+        );
+        Ok(amount)
     }
     
-    fn get_next_application_id(env: &Env) -> u64 {
-        env.storage().persistent()
-            .get(&symbol_short!("app_cnt"))
-            .unwrap_or(1)
+    /// Employer recovers the remaining stake to whoever staked it once the job is closed and nothing is left Pending;
+    /// payouts owed to breached applications stay behind until their candidates claim them
+    pub fn withdraw_response_stake(env: Env, employer: Address, job_id: u64) -> Result<i128, Error> {
+        employer.require_auth();
+        
+        let job = Self::load_managed_job(&env, &employer, job_id)?;
+        if job.is_active {
+            return Err(Error::InvalidTransition);
+        }
+        
+        let key = (symbol_short!("stake"), job_id);
+        let mut stake: ResponseStake = env.storage().persistent()
+            .get(&key)
+            .ok_or(Error::EscrowNotFound)?;
+        
+        let mut owed = 0;
+        for application_id in Self::get_index(&env, (symbol_short!("job_apps"), job_id)).iter() {
+            if let Some(application) = Self::get_application(env.clone(), application_id) {
+                if application.status == ApplicationStatus::Pending {
+                    return Err(Error::InvalidTransition);
+                }
+                let claimed = env.storage().persistent().has(&(symbol_short!("stk_clm"), application_id));
+                if !claimed && Self::breached_sla(&env, &application, &stake) {
+                    owed += stake.payout;
+                }
+            }
+        }
+        
+        let amount = (stake.balance - owed).max(0);
+        if amount > 0 {
+            token::Client::new(&env, &stake.token).transfer(&env.current_contract_address(), &stake.funder, &amount);
+        }
+        stake.balance -= amount;
+        if stake.balance == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &stake);
+        }
+        
+        env.events().publish((JOB, symbol_short!("unstake"), employer), (job_id, amount));
+        Ok(amount)
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
-
-    #[test]
-    fn test_job_posting_and_application() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, JobApplicationContract);
-        let client = JobApplicationContractClient::new(&env, &contract_id);
+    
+    /// Employer escrows a referral bounty paid to the referrer of the hired candidate; the same funder funding again tops up
+    pub fn fund_referral_bounty(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<ReferralBounty, Error> {
+        employer.require_auth();
         
-        let employer = Address::generate(&env);
-        let candidate = Address::generate(&env);
+        let job = Self::load_managed_job(&env, &employer, job_id)?;
+        if !job.is_active {
+            return Err(Error::JobInactive);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidDeposit);
+        }
         
-        env.mock_all_auths();
+        let key = (symbol_short!("bounty"), job_id);
+        let mut bounty = match env.storage().persistent().get::<_, ReferralBounty>(&key) {
+            Some(existing) if existing.funder != employer || existing.token != token => return Err(Error::InvalidDeposit),
+            Some(existing) => existing,
+            None => ReferralBounty { funder: employer.clone(), token: token.clone(), amount: 0 },
+        };
         
-        // Employer posts job
-        let job = client.post_job(
-            &employer,
-            &String::from_str(&env, "Senior Rust Developer"),
-            &String::from_str(&env, "QmJobDetails"),
-            &String::from_str(&env, "100k-150k"),
-        );
+        token::Client::new(&env, &token).transfer(&employer, &env.current_contract_address(), &amount);
+        bounty.amount += amount;
+        env.storage().persistent().set(&key, &bounty);
         
-        assert_eq!(job.job_id, 1);
-        assert_eq!(job.is_active, true);
+        env.events().publish((JOB, symbol_short!("bounty"), employer), (job_id, bounty.amount));
+        Ok(bounty)
+    }
+    
+    /// Employer reclaims an unpaid bounty once the job is closed with no applications left open
+    pub fn reclaim_referral_bounty(env: Env, employer: Address, job_id: u64) -> Result<i128, Error> {
+        employer.require_auth();
         
-        // Candidate applies
-        let application = client.apply_to_job(
-            &candidate,
-            &1,
-            &String::from_str(&env, "QmProfile"),
-            &String::from_str(&env, "QmCoverLetter"),
-        );
+        let job = Self::load_managed_job(&env, &employer, job_id)?;
+        if job.is_active || job.active_application_count > 0 {
+            return Err(Error::InvalidTransition);
+        }
         
-        assert_eq!(application.status, ApplicationStatus::Pending);
-        assert_eq!(application.candidate, candidate);
+        Self::refund_bounty(&env, &job).ok_or(Error::EscrowNotFound)
     }
     
-    #[test]
-    fn test_application_verification_flow() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, JobApplicationContract);
-        let client = JobApplicationContractClient::new(&env, &contract_id);
+    /// Get the referral bounty escrowed for a job
+    pub fn get_referral_bounty(env: Env, job_id: u64) -> Option<ReferralBounty> {
+        env.storage().persistent().get(&(symbol_short!("bounty"), job_id))
+    }
+    
+    /// Get the responsiveness stake backing a job
+    pub fn get_response_stake(env: Env, job_id: u64) -> Option<ResponseStake> {
+        env.storage().persistent().get(&(symbol_short!("stake"), job_id))
+    }
+    
+    /// Get the full status history of an application, oldest first
+    pub fn get_application_history(env: Env, application_id: u64) -> Vec<StatusChange> {
+        env.storage().persistent()
+            .get(&(symbol_short!("app_hist"), application_id))
+            .unwrap_or(Vec::new(&env))
+    }
+    
+    /// Employer sets how long a rejected or withdrawn candidate must wait to re-apply
+    pub fn set_reapply_cooldown(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        cooldown: Option<u64>,
+    ) -> Result<JobPosting, Error> {
+        employer.require_auth();
         
-        let employer = Address::generate(&env);
-        let candidate = Address::generate(&env);
+        let job_key = (symbol_short!("job"), job_id);
+        let mut job = Self::load_managed_job(&env, &employer, job_id)?;
         
-        env.mock_all_auths();
+        job.reapply_cooldown = cooldown;
+        env.storage().persistent().set(&job_key, &job);
+        env.events().publish((JOB, symbol_short!("cooldown"), employer), (job_id, cooldown));
+        Ok(job)
+    }
+    
+    /// Employer edits a job posting; the previous description is kept in the revision list
+    pub fn update_job(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        title: String,
+        ipfs_details: String,
+        compensation: Compensation,
+    ) -> Result<JobPosting, Error> {
+        employer.require_auth();
         
-        // Create job and application
-        client.post_job(
-            &employer,
-            &String::from_str(&env, "Developer"),
-            &String::from_str(&env, "QmJob"),
-            &String::from_str(&env, "80k"),
-        );
+        let job_key = (symbol_short!("job"), job_id);
+        let mut job = Self::load_managed_job(&env, &employer, job_id)?;
+        if !compensation.is_valid() {
+            return Err(Error::InvalidCompensation);
+        }
         
-        client.apply_to_job(
-            &candidate,
-            &1,
-            &String::from_str(&env, "QmProfile"),
-            &String::from_str(&env, "QmCover"),
-        );
+        job.revision += 1;
+        let revisions_key = (symbol_short!("job_revs"), job_id);
+        let mut revisions: Vec<JobRevision> = env.storage().persistent()
+            .get(&revisions_key)
+            .unwrap_or(Vec::new(&env));
+        revisions.push_back(JobRevision {
+            revision: job.revision,
+            previous_ipfs_details: job.ipfs_details.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&revisions_key, &revisions);
         
-        // Employer verifies
-        let verified = client.verify_application(
-            &employer,
-            &1,
+        job.title = title;
+        job.ipfs_details = ipfs_details;
+        job.compensation = compensation;
+        env.storage().persistent().set(&job_key, &job);
+        
+        env.events().publish((JOB, symbol_short!("update"), employer), (job_id, job.revision));
+        Ok(job)
+    }
+    
+    /// Get the edit history of a job posting, oldest first
+    pub fn get_job_revisions(env: Env, job_id: u64) -> Vec<JobRevision> {
+        env.storage().persistent()
+            .get(&(symbol_short!("job_revs"), job_id))
+            .unwrap_or(Vec::new(&env))
+    }
+    
+    /// Employer sets the on-chain checks applicants must pass
+    pub fn set_job_requirements(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        requirements: JobRequirements,
+    ) -> Result<(), Error> {
+        employer.require_auth();
+        
+        Self::load_managed_job(&env, &employer, job_id)?;
+        
+        env.storage().persistent().set(&(symbol_short!("job_req"), job_id), &requirements);
+        env.events().publish((JOB, symbol_short!("require"), employer), (job_id, requirements));
+        Ok(())
+    }
+    
+    /// Get the checks applicants to a job must pass
+    pub fn get_job_requirements(env: Env, job_id: u64) -> Option<JobRequirements> {
+        env.storage().persistent().get(&(symbol_short!("job_req"), job_id))
+    }
+    
+    /// Get a candidate's latest application to a job
+    pub fn get_candidate_application(env: Env, job_id: u64, candidate: Address) -> Option<Application> {
+        let application_id: u64 = env.storage().persistent()
+            .get(&(symbol_short!("job_cand"), job_id, candidate))?;
+        Self::get_application(env, application_id)
+    }
+    
+    /// Get job details
+    pub fn get_job(env: Env, job_id: u64) -> Option<JobPosting> {
+        env.storage().persistent().get(&(symbol_short!("job"), job_id))
+    }
+    
+    /// Get application details
+    pub fn get_application(env: Env, application_id: u64) -> Option<Application> {
+        env.storage().persistent().get(&(symbol_short!("app"), application_id))
+    }
+    
+    /// List jobs accepting applications, oldest first; pass 0 as the cursor for the first page
+    pub fn get_active_jobs(env: Env, cursor: u64, limit: u32) -> JobPage {
+        let ids = Self::get_index(&env, symbol_short!("act_jobs"));
+        let (jobs, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |job_id| {
+            Self::get_job(env.clone(), job_id).filter(|job| Self::accepts_applications(&env, job))
+        });
+        JobPage { jobs, next_cursor }
+    }
+    
+    /// List open jobs whose pay band overlaps the candidate's expectation; an equity expectation only matches jobs offering equity
+    pub fn get_jobs_by_compensation(env: Env, expectation: Compensation, cursor: u64, limit: u32) -> JobPage {
+        let ids = Self::get_index(&env, symbol_short!("act_jobs"));
+        let (jobs, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |job_id| {
+            Self::get_job(env.clone(), job_id).filter(|job| {
+                Self::accepts_applications(&env, job)
+                    && job.compensation.overlaps(&expectation)
+                    && (job.compensation.equity || !expectation.equity)
+            })
+        });
+        JobPage { jobs, next_cursor }
+    }
+    
+    /// List an employer's jobs (active or closed), oldest first
+    pub fn get_jobs_by_employer(env: Env, employer: Address, cursor: u64, limit: u32) -> JobPage {
+        let ids = Self::get_index(&env, (symbol_short!("emp_jobs"), employer));
+        let (jobs, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |job_id| {
+            Self::get_job(env.clone(), job_id)
+        });
+        JobPage { jobs, next_cursor }
+    }
+    
+    /// List applications to a job, optionally only those in a given status. A sparse filter may return
+    /// a short or empty page with a cursor; keep paging until the cursor is None
+    pub fn get_job_applications(
+        env: Env,
+        job_id: u64,
+        status: Option<ApplicationStatus>,
+        cursor: u64,
+        limit: u32,
+    ) -> ApplicationPage {
+        let ids = Self::get_index(&env, (symbol_short!("job_apps"), job_id));
+        let (applications, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |application_id| {
+            Self::get_application(env.clone(), application_id)
+                .filter(|application| status.as_ref().is_none_or(|s| application.status == *s))
+        });
+        ApplicationPage { applications, next_cursor }
+    }
+    
+    /// List a candidate's applications, oldest first
+    pub fn get_candidate_applications(
+        env: Env,
+        candidate: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> ApplicationPage {
+        let ids = Self::get_index(&env, (symbol_short!("cand_apps"), candidate));
+        let (applications, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |application_id| {
+            Self::get_application(env.clone(), application_id)
+        });
+        ApplicationPage { applications, next_cursor }
+    }
+    
+    /// Employer or organization recruiter closes a job, optionally moving open applications to `PositionClosed`
+    pub fn close_job(
+        env: Env,
+        employer: Address,
+        job_id: u64,
+        close_open_applications: bool,
+    ) -> Result<JobPosting, Error> {
+        employer.require_auth();
+        
+        let job = Self::get_job(env.clone(), job_id).ok_or(Error::JobNotFound)?;
+        if !Self::can_manage_job(&env, &employer, &job) {
+            return Err(Error::NotAuthorized);
+        }
+        
+        Self::close(&env, job_id, close_open_applications)
+    }
+    
+    /// Anyone can persist the closure of a job whose deadline has passed
+    pub fn close_expired_job(env: Env, job_id: u64) -> Result<JobPosting, Error> {
+        let job = Self::get_job(env.clone(), job_id).ok_or(Error::JobNotFound)?;
+        if !job.is_active {
+            return Err(Error::JobInactive);
+        }
+        if !Self::deadline_passed(&env, &job) {
+            return Err(Error::InvalidTransition);
+        }
+        
+        Self::close(&env, job_id, false)
+    }
+    
+    /// Check if a job currently accepts applications
+    pub fn is_job_open(env: Env, job_id: u64) -> bool {
+        match Self::get_job(env.clone(), job_id) {
+            Some(job) => Self::accepts_applications(&env, &job),
+            None => false,
+        }
+    }
+    
+    // Helper functions
+    #[allow(clippy::too_many_arguments)]
+    fn create_job(
+        env: &Env,
+        employer: Address,
+        organization: Option<u64>,
+        title: String,
+        ipfs_details: String,
+        compensation: Compensation,
+        deadline: Option<u64>,
+        max_applications: Option<u32>,
+        stages: Vec<String>,
+    ) -> Result<JobPosting, Error> {
+        if !compensation.is_valid() {
+            return Err(Error::InvalidCompensation);
+        }
+        Self::validate_stages(&stages)?;
+        
+        let job_id = Self::get_next_job_id(env);
+        
+        let job = JobPosting {
+            job_id,
+            employer: employer.clone(),
+            title,
+            ipfs_details,
+            compensation,
+            is_active: true,
+            created_at: env.ledger().timestamp(),
+            application_count: 0,
+            active_application_count: 0,
+            reapply_cooldown: None,
+            deadline,
+            max_applications,
+            revision: 1,
+            organization,
+            stages,
+        };
+        
+        env.storage().persistent().set(&(symbol_short!("job"), job_id), &job);
+        env.storage().persistent().set(&symbol_short!("job_cnt"), &(job_id + 1));
+        
+        // Index for listings
+        Self::push_index(env, symbol_short!("act_jobs"), job_id);
+        Self::push_index(env, (symbol_short!("emp_jobs"), employer.clone()), job_id);
+        if let Some(org_id) = organization {
+            Self::push_index(env, (symbol_short!("org_jobs"), org_id), job_id);
+        }
+        
+        env.events().publish((JOB, symbol_short!("post"), employer), job.clone());
+        
+        Ok(job)
+    }
+    
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env.storage().persistent()
+            .get(&symbol_short!("admin"))
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+    
+    // Enforce the job's requirements and return the candidate's on-chain profile CID
+    fn check_requirements(env: &Env, job_id: u64, candidate: &Address) -> Result<String, Error> {
+        let requirements = Self::get_job_requirements(env.clone(), job_id);
+        let profiles: Option<Address> = env.storage().persistent().get(&symbol_short!("profiles"));
+        
+        let profiles = match (profiles, &requirements) {
+            (Some(profiles), _) => CandidateProfileClient::new(env, &profiles),
+            (None, None) => return Ok(String::from_str(env, "")),
+            (None, Some(_)) => return Err(Error::NotInitialized),
+        };
+        let profile_cid = profiles.get_profile_cid(candidate);
+        
+        if let Some(requirements) = requirements {
+            if requirements.require_profile && profile_cid.is_none() {
+                return Err(Error::ProfileRequired);
+            }
+            if requirements.min_verifications > 0 {
+                let verifications: Address = env.storage().persistent()
+                    .get(&symbol_short!("doc_verif"))
+                    .ok_or(Error::NotInitialized)?;
+                let count = DocumentVerificationClient::new(env, &verifications).get_verification_count(candidate);
+                if count < requirements.min_verifications {
+                    return Err(Error::InsufficientVerifications);
+                }
+            }
+            for doc_type in requirements.required_doc_types.iter() {
+                if !profiles.has_verified_document_type(candidate, &doc_type) {
+                    return Err(Error::MissingVerifiedDocType);
+                }
+            }
+        }
+        
+        Ok(profile_cid.unwrap_or(String::from_str(env, "")))
+    }
+    
+    fn deadline_passed(env: &Env, job: &JobPosting) -> bool {
+        job.deadline.is_some_and(|deadline| env.ledger().timestamp() > deadline)
+    }
+    
+    fn accepts_applications(env: &Env, job: &JobPosting) -> bool {
+        job.is_active
+            && !Self::deadline_passed(env, job)
+            && job.max_applications.is_none_or(|max| job.application_count < max)
+    }
+    
+    fn close(env: &Env, job_id: u64, close_open_applications: bool) -> Result<JobPosting, Error> {
+        let job_key = (symbol_short!("job"), job_id);
+        let mut job: JobPosting = env.storage().persistent()
+            .get(&job_key)
+            .ok_or(Error::JobNotFound)?;
+        if !job.is_active {
+            return Err(Error::JobInactive);
+        }
+        
+        job.is_active = false;
+        env.storage().persistent().set(&job_key, &job);
+        Self::remove_index(env, symbol_short!("act_jobs"), job_id);
+        env.events().publish((JOB, symbol_short!("close"), job.employer.clone()), job_id);
+        
+        if close_open_applications {
+            let employer = job.employer.clone();
+            for application_id in Self::get_index(env, (symbol_short!("job_apps"), job_id)).iter() {
+                let mut application: Application = match env.storage().persistent().get(&(symbol_short!("app"), application_id)) {
+                    Some(application) => application,
+                    None => continue,
+                };
+                if !application.status.is_terminal() {
+                    Self::record_status(env, &mut application, ApplicationStatus::PositionClosed, &employer, None);
+                }
+            }
+            // record_status keeps the job's counters current
+            job = env.storage().persistent().get(&job_key).ok_or(Error::JobNotFound)?;
+        }
+        
+        // Nobody left to hire, so an unpaid bounty goes back to the employer
+        if job.active_application_count == 0 {
+            Self::refund_bounty(env, &job);
+        }
+        
+        Ok(job)
+    }
+    
+    // Re-applying is only allowed after a rejection or withdrawal, once the cooldown has passed
+    fn check_reapply(env: &Env, job: &JobPosting, previous_id: u64) -> Result<(), Error> {
+        let previous: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), previous_id))
+            .ok_or(Error::ApplicationNotFound)?;
+        
+        if !matches!(previous.status, ApplicationStatus::Rejected | ApplicationStatus::Withdrawn) {
+            return Err(Error::AlreadyApplied);
+        }
+        let cooldown = job.reapply_cooldown.ok_or(Error::AlreadyApplied)?;
+        
+        let history: Vec<StatusChange> = env.storage().persistent()
+            .get(&(symbol_short!("app_hist"), previous_id))
+            .unwrap_or(Vec::new(env));
+        let closed_at = history.last().map(|change| change.timestamp).unwrap_or(previous.applied_at);
+        if env.ledger().timestamp() < closed_at.saturating_add(cooldown) {
+            return Err(Error::ReapplyCooldown);
+        }
+        Ok(())
+    }
+    
+    // Job poster, or a member of the owning organization allowed to manage applications
+    // Organization jobs follow current membership, so removed posters lose access
+    fn can_manage_job(env: &Env, actor: &Address, job: &JobPosting) -> bool {
+        match job.organization {
+            Some(org_id) => Self::get_member_role(env.clone(), org_id, actor.clone())
+                .is_some_and(|role| role.can_manage_applications()),
+            None => *actor == job.employer,
+        }
+    }
+    
+    // The poster or anyone seated in the owning organization, whatever their role
+    fn is_job_insider(env: &Env, actor: &Address, job: &JobPosting) -> bool {
+        *actor == job.employer
+            || job.organization.is_some_and(|org_id| Self::get_member_role(env.clone(), org_id, actor.clone()).is_some())
+    }
+    
+    fn load_for_reviewer(env: &Env, actor: &Address, application_id: u64) -> Result<Application, Error> {
+        let application: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), application_id))
+            .ok_or(Error::ApplicationNotFound)?;
+        
+        let job = Self::get_job(env.clone(), application.job_id).ok_or(Error::JobNotFound)?;
+        if !Self::can_manage_job(env, actor, &job) {
+            return Err(Error::NotAuthorized);
+        }
+        Ok(application)
+    }
+    
+    fn load_managed_job(env: &Env, actor: &Address, job_id: u64) -> Result<JobPosting, Error> {
+        let job = Self::get_job(env.clone(), job_id).ok_or(Error::JobNotFound)?;
+        if !Self::can_manage_job(env, actor, &job) {
+            return Err(Error::NotAuthorized);
+        }
+        Ok(job)
+    }
+    
+    // Where a job's forfeits go: the organization's owner, or the poster of a personal job
+    fn job_treasury(env: &Env, job: &JobPosting) -> Address {
+        job.organization
+            .and_then(|org_id| Self::get_organization(env.clone(), org_id))
+            .map(|organization| organization.owner)
+            .unwrap_or(job.employer.clone())
+    }
+    
+    // Validate a move against the transition table, then record it
+    fn transition(
+        env: &Env,
+        application: &mut Application,
+        next: ApplicationStatus,
+        actor: &Address,
+        note: Option<String>,
+    ) -> Result<(), Error> {
+        if !application.status.can_transition_to(&next) {
+            return Err(Error::InvalidTransition);
+        }
+        Self::record_status(env, application, next, actor, note);
+        Ok(())
+    }
+    
+    fn record_status(
+        env: &Env,
+        application: &mut Application,
+        next: ApplicationStatus,
+        actor: &Address,
+        note: Option<String>,
+    ) {
+        // Keep the job's open-application counter in step with terminal moves
+        if application.status.is_terminal() != next.is_terminal() {
+            let job_key = (symbol_short!("job"), application.job_id);
+            if let Some(mut job) = env.storage().persistent().get::<_, JobPosting>(&job_key) {
+                if next.is_terminal() {
+                    job.active_application_count = job.active_application_count.saturating_sub(1);
+                } else {
+                    job.active_application_count += 1;
+                }
+                env.storage().persistent().set(&job_key, &job);
+            }
+        }
+        
+        // Any move out of Pending other than a spam flag returns the deposit
+        if let Some(deposit) = Self::take_deposit(env, application.application_id) {
+            token::Client::new(env, &deposit.token).transfer(
+                &env.current_contract_address(),
+                &application.candidate,
+                &deposit.amount,
+            );
+            env.events().publish(
+                (APPLICATION, symbol_short!("refund"), application.job_id, application.candidate.clone()),
+                (application.application_id, deposit.amount),
+            );
+        }
+        
+        // Withdrawal is the candidate's move, not a review
+        if next != ApplicationStatus::Withdrawn {
+            application.reviewed_at = Some(env.ledger().timestamp());
+            if application.first_response_at.is_none() {
+                application.first_response_at = application.reviewed_at;
+            }
+        }
+        application.status = next;
+        
+        env.storage().persistent().set(&(symbol_short!("app"), application.application_id), application);
+        Self::push_history(env, application.application_id, &application.status, actor, note);
+        Self::publish_status(env, application);
+    }
+    
+    // Whether the employer's first response came after the SLA; still-Pending applications are measured
+    // against now. A candidate who withdrew before any response was not ghosted.
+    fn breached_sla(env: &Env, application: &Application, stake: &ResponseStake) -> bool {
+        let responded_at = match application.first_response_at {
+            Some(timestamp) => timestamp,
+            None if application.status == ApplicationStatus::Withdrawn => return false,
+            None => env.ledger().timestamp(),
+        };
+        responded_at.saturating_sub(application.applied_at) > stake.sla
+    }
+    
+    fn take_deposit(env: &Env, application_id: u64) -> Option<Deposit> {
+        let key = (symbol_short!("app_dep"), application_id);
+        let deposit: Deposit = env.storage().persistent().get(&key)?;
+        env.storage().persistent().remove(&key);
+        Some(deposit)
+    }
+    
+    fn take_bounty(env: &Env, job_id: u64) -> Option<ReferralBounty> {
+        let key = (symbol_short!("bounty"), job_id);
+        let bounty: ReferralBounty = env.storage().persistent().get(&key)?;
+        env.storage().persistent().remove(&key);
+        Some(bounty)
+    }
+    
+    // The job's bounty goes to the referrer of the first hire, whether accepted directly or through an offer
+    fn pay_referral_bounty(env: &Env, application: &Application) {
+        let Some(referrer) = application.referrer.clone() else {
+            return;
+        };
+        if let Some(bounty) = Self::take_bounty(env, application.job_id) {
+            token::Client::new(env, &bounty.token).transfer(
+                &env.current_contract_address(),
+                &referrer,
+                &bounty.amount,
+            );
+            env.events().publish(
+                (APPLICATION, symbol_short!("bounty"), application.job_id, referrer),
+                (application.application_id, bounty.amount),
+            );
+        }
+    }
+    
+    fn refund_bounty(env: &Env, job: &JobPosting) -> Option<i128> {
+        let bounty = Self::take_bounty(env, job.job_id)?;
+        token::Client::new(env, &bounty.token).transfer(
+            &env.current_contract_address(),
+            &bounty.funder,
+            &bounty.amount,
+        );
+        env.events().publish((JOB, symbol_short!("unbounty"), bounty.funder), (job.job_id, bounty.amount));
+        Some(bounty.amount)
+    }
+    
+    fn push_history(
+        env: &Env,
+        application_id: u64,
+        status: &ApplicationStatus,
+        actor: &Address,
+        note: Option<String>,
+    ) {
+        let key = (symbol_short!("app_hist"), application_id);
+        let mut history: Vec<StatusChange> = env.storage().persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        history.push_back(StatusChange {
+            status: status.clone(),
+            actor: actor.clone(),
+            timestamp: env.ledger().timestamp(),
+            note,
+        });
+        env.storage().persistent().set(&key, &history);
+    }
+    
+    fn publish_status(env: &Env, application: &Application) {
+        env.events().publish(
+            (APPLICATION, symbol_short!("status"), application.job_id, application.candidate.clone()),
+            (application.application_id, application.status.clone()),
+        );
+    }
+    
+    fn get_index<K>(env: &Env, key: K) -> Vec<u64>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+    }
+    
+    fn push_index<K>(env: &Env, key: K, id: u64)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+    }
+    
+    fn remove_index<K>(env: &Env, key: K, id: u64)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut ids: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if let Ok(index) = ids.binary_search(id) {
+            ids.remove(index);
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+    
+    // Walk an ascending id index from just after `cursor`, keeping items `load` returns. The walk stops
+    // after MAX_PAGE_SCAN entries so sparse filters stay within budget; the cursor resumes the scan
+    fn collect_page<T, F>(env: &Env, ids: &Vec<u64>, cursor: u64, limit: u32, mut load: F) -> (Vec<T>, Option<u64>)
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+        F: FnMut(u64) -> Option<T>,
+    {
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut items = Vec::new(env);
+        let mut index = match ids.binary_search(cursor) {
+            Ok(found) => found + 1,
+            Err(insert_at) => insert_at,
+        };
+        
+        let mut last_seen = cursor;
+        let mut scanned = 0;
+        while index < ids.len() && items.len() < limit && scanned < MAX_PAGE_SCAN {
+            let id = ids.get_unchecked(index);
+            if let Some(item) = load(id) {
+                items.push_back(item);
+            }
+            last_seen = id;
+            index += 1;
+            scanned += 1;
+        }
+        
+        let next_cursor = if index < ids.len() { Some(last_seen) } else { None };
+        (items, next_cursor)
+    }
+    
+    fn get_next_job_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("job_cnt"))
+            .unwrap_or(1)
+    }
+    
+    fn get_next_application_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("app_cnt"))
+            .unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Events, Ledger}, Env, IntoVal};
+    
+    fn salary(env: &Env, min: i128, max: i128) -> Compensation {
+        Compensation {
+            min,
+            max,
+            currency: Currency::Iso(String::from_str(env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        }
+    }
+    
+    #[test]
+    fn test_job_posting_and_application() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        // Employer posts job
+        let job = client.post_job(
+            &employer,
+            &String::from_str(&env, "Senior Rust Developer"),
+            &String::from_str(&env, "QmJobDetails"),
+            &salary(&env, 100_000, 150_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        
+        assert_eq!(job.job_id, 1);
+        assert_eq!(job.is_active, true);
+        
+        // Candidate applies
+        let application = client.apply_to_job(
+            &candidate,
+            &1,
+            &String::from_str(&env, "QmCoverLetter"),
+            &None,
+        );
+        
+        assert_eq!(application.status, ApplicationStatus::Pending);
+        assert_eq!(application.candidate, candidate);
+        
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (APPLICATION, symbol_short!("submit"), 1u64, candidate.clone()).into_val(&env));
+    }
+    
+    #[test]
+    fn test_application_verification_flow() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        // Create job and application
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        
+        client.apply_to_job(
+            &candidate,
+            &1,
+            &String::from_str(&env, "QmCover"),
+            &None,
+        );
+        
+        // Employer verifies
+        let verified = client.verify_application(
+            &employer,
+            &1,
             &String::from_str(&env, "All documents verified"),
         );
         
         assert_eq!(verified.status, ApplicationStatus::Verified);
+        
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(topics, (APPLICATION, symbol_short!("status"), 1u64, candidate.clone()).into_val(&env));
+    }
+    
+    #[test]
+    fn test_typed_errors() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        let cover = String::from_str(&env, "QmCover");
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::JobNotFound)));
+        assert_eq!(client.try_review_application(&employer, &1).err(), Some(Ok(Error::ApplicationNotFound)));
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &cover, &None);
+        
+        assert_eq!(client.try_accept_application(&candidate, &1).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_close_job(&candidate, &1, &false).err(), Some(Ok(Error::NotAuthorized)));
+        
+        client.close_job(&employer, &1, &false);
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::JobInactive)));
+    }
+    
+    #[test]
+    fn test_status_transitions_and_history() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(
+            &candidate,
+            &1,
+            &String::from_str(&env, "QmCover"),
+            &None,
+        );
+        
+        // Cannot accept straight from Pending
+        assert_eq!(client.try_accept_application(&employer, &1).err(), Some(Ok(Error::InvalidTransition)));
+        
+        client.review_application(&employer, &1);
+        client.reject_application(&employer, &1, &String::from_str(&env, "Not a fit"));
+        
+        // Rejected is terminal
+        assert_eq!(client.try_accept_application(&employer, &1).err(), Some(Ok(Error::InvalidTransition)));
+        assert_eq!(client.try_review_application(&employer, &1).err(), Some(Ok(Error::InvalidTransition)));
+        
+        // ...until explicitly reopened
+        let reopened = client.reopen_application(&employer, &1, &String::from_str(&env, "Second look"));
+        assert_eq!(reopened.status, ApplicationStatus::UnderReview);
+        assert_eq!(client.try_reopen_application(&employer, &1, &String::from_str(&env, "Again")).err(), Some(Ok(Error::InvalidTransition)));
+        client.accept_application(&employer, &1);
+        
+        let history = client.get_application_history(&1);
+        assert_eq!(history.len(), 5);
+        assert_eq!(history.get(0).unwrap().status, ApplicationStatus::Pending);
+        assert_eq!(history.get(0).unwrap().actor, candidate);
+        assert_eq!(history.get(2).unwrap().note, Some(String::from_str(&env, "Not a fit")));
+        assert_eq!(history.get(4).unwrap().status, ApplicationStatus::Accepted);
+        assert_eq!(history.get(4).unwrap().actor, employer);
+    }
+    
+    #[test]
+    fn test_candidate_withdrawal() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(
+            &candidate,
+            &1,
+            &String::from_str(&env, "QmCover"),
+            &None,
+        );
+        client.review_application(&employer, &1);
+        assert_eq!(client.get_job(&1).unwrap().active_application_count, 1);
+        
+        // Only the applicant can withdraw
+        assert_eq!(client.try_withdraw_application(&employer, &1).err(), Some(Ok(Error::NotAuthorized)));
+        
+        let withdrawn = client.withdraw_application(&candidate, &1);
+        assert_eq!(withdrawn.status, ApplicationStatus::Withdrawn);
+        
+        let job = client.get_job(&1).unwrap();
+        assert_eq!(job.application_count, 1);
+        assert_eq!(job.active_application_count, 0);
+        
+        // Withdrawn applications are closed to both sides
+        assert_eq!(client.try_withdraw_application(&candidate, &1).err(), Some(Ok(Error::InvalidTransition)));
+        assert_eq!(client.try_verify_application(&employer, &1, &String::from_str(&env, "ok")).err(), Some(Ok(Error::InvalidTransition)));
+        assert_eq!(client.try_reopen_application(&employer, &1, &String::from_str(&env, "back")).err(), Some(Ok(Error::InvalidTransition)));
+    }
+    
+    #[test]
+    fn test_duplicate_application_and_cooldown() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &cover, &None);
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::AlreadyApplied)));
+        
+        // Without a cooldown a rejected candidate can never re-apply
+        client.reject_application(&employer, &1, &String::from_str(&env, "Not now"));
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::AlreadyApplied)));
+        
+        client.set_reapply_cooldown(&employer, &1, &Some(3600));
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::ReapplyCooldown)));
+        
+        env.ledger().with_mut(|ledger| ledger.timestamp += 3600);
+        let second = client.apply_to_job(&candidate, &1, &cover, &None);
+        assert_eq!(second.application_id, 2);
+        assert_eq!(client.get_candidate_application(&1, &candidate).unwrap().application_id, 2);
+        assert_eq!(client.get_job(&1).unwrap().application_count, 2);
+    }
+    
+    #[test]
+    fn test_paginated_listings() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let other_employer = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        for i in 0..5u32 {
+            let owner = if i == 3 { &other_employer } else { &employer };
+            client.post_job(
+                owner,
+                &String::from_str(&env, "Developer"),
+                &String::from_str(&env, "QmJob"),
+                &salary(&env, 80_000, 80_000),
+                &None,
+                &None,
+                &Vec::new(&env),
+            );
+        }
+        client.close_job(&employer, &2, &false);
+        
+        // Active jobs skip the closed one and page by cursor
+        let page = client.get_active_jobs(&0, &2);
+        assert_eq!(page.jobs.len(), 2);
+        assert_eq!(page.jobs.get(1).unwrap().job_id, 3);
+        assert_eq!(page.next_cursor, Some(3));
+        let page = client.get_active_jobs(&3, &2);
+        assert_eq!(page.jobs.len(), 2);
+        assert_eq!(page.next_cursor, None);
+        
+        let page = client.get_jobs_by_employer(&employer, &0, &10);
+        assert_eq!(page.jobs.len(), 4);
+        
+        // Applications by job, with and without a status filter
+        let mut candidates = Vec::new(&env);
+        for _ in 0..3 {
+            let candidate = Address::generate(&env);
+            client.apply_to_job(&candidate, &1, &cover, &None);
+            candidates.push_back(candidate);
+        }
+        client.apply_to_job(&candidates.get(0).unwrap(), &3, &cover, &None);
+        client.reject_application(&employer, &2, &String::from_str(&env, "No"));
+        
+        assert_eq!(client.get_job_applications(&1, &None, &0, &10).applications.len(), 3);
+        let pending = client.get_job_applications(&1, &Some(ApplicationStatus::Pending), &0, &10);
+        assert_eq!(pending.applications.len(), 2);
+        assert_eq!(pending.applications.get(1).unwrap().application_id, 3);
+        
+        let mine = client.get_candidate_applications(&candidates.get(0).unwrap(), &0, &10);
+        assert_eq!(mine.applications.len(), 2);
+        assert_eq!(mine.applications.get(1).unwrap().job_id, 3);
+    }
+    
+    #[test]
+    fn test_sparse_filter_scan_is_bounded() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Developer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        for _ in 0..(MAX_PAGE_SCAN + 2) {
+            client.apply_to_job(&Address::generate(&env), &1, &cover, &None);
+        }
+        let last = u64::from(MAX_PAGE_SCAN + 2);
+        client.reject_application(&employer, &last, &String::from_str(&env, "No"));
+        
+        // The only match sits past the scan cap: the first page is empty but resumable, and each
+        // call fits the default budget
+        let rejected = Some(ApplicationStatus::Rejected);
+        env.budget().reset_default();
+        let page = client.get_job_applications(&1, &rejected, &0, &10);
+        assert_eq!(page.applications.len(), 0);
+        assert_eq!(page.next_cursor, Some(u64::from(MAX_PAGE_SCAN)));
+        env.budget().reset_default();
+        let page = client.get_job_applications(&1, &rejected, &page.next_cursor.unwrap(), &10);
+        assert_eq!(page.applications.get(0).unwrap().application_id, last);
+        assert_eq!(page.next_cursor, None);
+    }
+    
+    #[test]
+    fn test_deadline_cap_and_close_outcomes() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        // Job 1 has a deadline, job 2 takes two applicants, job 3 is unlimited
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Deadline"),
+            &String::from_str(&env, "QmJob1"),
+            &salary(&env, 80_000, 80_000),
+            &Some(2_000),
+            &None,
+            &Vec::new(&env),
+        );
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Capped"),
+            &String::from_str(&env, "QmJob2"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &Some(2),
+            &Vec::new(&env),
+        );
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Open"),
+            &String::from_str(&env, "QmJob3"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        
+        client.apply_to_job(&Address::generate(&env), &2, &cover, &None);
+        client.apply_to_job(&Address::generate(&env), &2, &cover, &None);
+        let capped = client.get_job(&2).unwrap();
+        assert!(!capped.is_active);
+        assert_eq!(client.try_apply_to_job(&Address::generate(&env), &2, &cover, &None).err(), Some(Ok(Error::JobInactive)));
+        // Applications received before the cap stay open for review
+        assert_eq!(client.get_application(&2).unwrap().status, ApplicationStatus::Pending);
+        
+        env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+        assert!(!client.is_job_open(&1));
+        assert_eq!(client.try_apply_to_job(&Address::generate(&env), &1, &cover, &None).err(), Some(Ok(Error::DeadlinePassed)));
+        assert_eq!(client.get_active_jobs(&0, &10).jobs.len(), 1);
+        assert!(!client.close_expired_job(&1).is_active);
+        assert_eq!(client.try_close_expired_job(&3).err(), Some(Ok(Error::InvalidTransition)));
+        
+        // Closing with outcomes resolves everything still open
+        let candidate = Address::generate(&env);
+        client.apply_to_job(&candidate, &3, &cover, &None);
+        client.review_application(&employer, &3);
+        let closed = client.close_job(&employer, &3, &true);
+        assert_eq!(closed.active_application_count, 0);
+        assert_eq!(client.get_application(&3).unwrap().status, ApplicationStatus::PositionClosed);
+        assert_eq!(client.try_close_job(&employer, &3, &true).err(), Some(Ok(Error::JobInactive)));
+    }
+    
+    #[test]
+    fn test_job_revisions() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Rust Devloper"),
+            &String::from_str(&env, "QmJobV1"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&first, &1, &cover, &None);
+        
+        // Only the posting employer may edit
+        assert_eq!(
+            client.try_update_job(
+                &first,
+                &1,
+                &String::from_str(&env, "Rust Developer"),
+                &String::from_str(&env, "QmJobV2"),
+                &salary(&env, 90_000, 90_000),
+            ).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        
+        env.ledger().with_mut(|ledger| ledger.timestamp = 500);
+        let job = client.update_job(
+            &employer,
+            &1,
+            &String::from_str(&env, "Rust Developer"),
+            &String::from_str(&env, "QmJobV2"),
+            &salary(&env, 90_000, 90_000),
+        );
+        assert_eq!(job.revision, 2);
+        assert_eq!(job.title, String::from_str(&env, "Rust Developer"));
+        assert_eq!(job.application_count, 1);
+        
+        let revisions = client.get_job_revisions(&1);
+        assert_eq!(revisions.len(), 1);
+        let revision = revisions.get(0).unwrap();
+        assert_eq!(revision.revision, 2);
+        assert_eq!(revision.previous_ipfs_details, String::from_str(&env, "QmJobV1"));
+        assert_eq!(revision.timestamp, 500);
+        
+        // Applications keep the revision they were submitted against
+        client.apply_to_job(&second, &1, &cover, &None);
+        assert_eq!(client.get_application(&1).unwrap().job_revision, 1);
+        assert_eq!(client.get_application(&2).unwrap().job_revision, 2);
+    }
+    
+    #[test]
+    fn test_compensation_filtering() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let title = String::from_str(&env, "Engineer");
+        let details = String::from_str(&env, "QmJob");
+        
+        env.mock_all_auths();
+        
+        assert_eq!(
+            client.try_post_job(&employer, &title, &details, &salary(&env, 150_000, 100_000), &None, &None, &Vec::new(&env)).err(),
+            Some(Ok(Error::InvalidCompensation))
+        );
+        
+        client.post_job(&employer, &title, &details, &salary(&env, 60_000, 80_000), &None, &None, &Vec::new(&env));
+        client.post_job(&employer, &title, &details, &salary(&env, 100_000, 150_000), &None, &None, &Vec::new(&env));
+        let mut with_equity = salary(&env, 120_000, 180_000);
+        with_equity.equity = true;
+        client.post_job(&employer, &title, &details, &with_equity, &None, &None, &Vec::new(&env));
+        let mut hourly = salary(&env, 50, 90);
+        hourly.period = PayPeriod::Hourly;
+        client.post_job(&employer, &title, &details, &hourly, &None, &None, &Vec::new(&env));
+        
+        let page = client.get_jobs_by_compensation(&salary(&env, 90_000, 130_000), &0, &10);
+        assert_eq!(page.jobs.len(), 2);
+        assert_eq!(page.jobs.get(0).unwrap().job_id, 2);
+        assert_eq!(page.jobs.get(1).unwrap().job_id, 3);
+        
+        let mut wants_equity = salary(&env, 90_000, 130_000);
+        wants_equity.equity = true;
+        let page = client.get_jobs_by_compensation(&wants_equity, &0, &10);
+        assert_eq!(page.jobs.len(), 1);
+        assert_eq!(page.jobs.get(0).unwrap().job_id, 3);
+        
+        // Currency must match exactly
+        let mut euros = salary(&env, 0, 1_000_000);
+        euros.currency = Currency::Iso(String::from_str(&env, "EUR"));
+        assert_eq!(client.get_jobs_by_compensation(&euros, &0, &10).jobs.len(), 0);
+        
+        client.close_job(&employer, &2, &false);
+        assert_eq!(client.get_jobs_by_compensation(&salary(&env, 90_000, 130_000), &0, &10).jobs.len(), 1);
+    }
+    
+    #[test]
+    fn test_application_deposit() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let reviewed = Address::generate(&env);
+        let withdrawn = Address::generate(&env);
+        let spammer = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        let minter = token::StellarAssetClient::new(&env, &asset.address());
+        for candidate in [&reviewed, &withdrawn, &spammer] {
+            minter.mint(candidate, &100);
+        }
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        assert_eq!(client.try_set_application_deposit(&employer, &1, &asset.address(), &-1).err(), Some(Ok(Error::InvalidDeposit)));
+        client.set_application_deposit(&employer, &1, &asset.address(), &10);
+        
+        client.apply_to_job(&reviewed, &1, &cover, &None);
+        client.apply_to_job(&withdrawn, &1, &cover, &None);
+        client.apply_to_job(&spammer, &1, &cover, &None);
+        assert_eq!(token.balance(&contract_id), 30);
+        assert_eq!(token.balance(&reviewed), 90);
+        assert_eq!(client.get_application_deposit(&1).unwrap().amount, 10);
+        
+        // Review and withdrawal refund; a spam flag forfeits to the employer
+        client.review_application(&employer, &1);
+        client.withdraw_application(&withdrawn, &2);
+        client.flag_spam(&employer, &3);
+        assert_eq!(token.balance(&reviewed), 100);
+        assert_eq!(token.balance(&withdrawn), 100);
+        assert_eq!(token.balance(&spammer), 90);
+        assert_eq!(token.balance(&employer), 10);
+        assert_eq!(token.balance(&contract_id), 0);
+        assert!(client.get_application_deposit(&1).is_none());
+        assert_eq!(client.get_application(&3).unwrap().status, ApplicationStatus::Rejected);
+        
+        // Only pending applications can be flagged
+        assert_eq!(client.try_flag_spam(&employer, &1).err(), Some(Ok(Error::InvalidTransition)));
+    }
+    
+    #[test]
+    fn test_response_stake() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let prompt = Address::generate(&env);
+        let late = Address::generate(&env);
+        let ghosted = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&employer, &100);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        assert_eq!(client.try_claim_response_payout(&prompt, &1).err(), Some(Ok(Error::ApplicationNotFound)));
+        client.stake_for_response(&employer, &1, &asset.address(), &100, &1_000, &30);
+        
+        client.apply_to_job(&prompt, &1, &cover, &None);
+        client.apply_to_job(&late, &1, &cover, &None);
+        client.apply_to_job(&ghosted, &1, &cover, &None);
+        
+        env.ledger().with_mut(|ledger| ledger.timestamp += 500);
+        client.review_application(&employer, &1);
+        assert_eq!(client.try_claim_response_payout(&prompt, &1).err(), Some(Ok(Error::SlaNotBreached)));
+        assert_eq!(client.try_claim_response_payout(&ghosted, &3).err(), Some(Ok(Error::SlaNotBreached)));
+        
+        // Reviewing after the SLA still leaves the breach on record
+        env.ledger().with_mut(|ledger| ledger.timestamp += 1_000);
+        client.reject_application(&employer, &2, &String::from_str(&env, "Late"));
+        assert_eq!(client.claim_response_payout(&late, &2), 30);
+        assert_eq!(client.claim_response_payout(&ghosted, &3), 30);
+        assert_eq!(client.try_claim_response_payout(&ghosted, &3).err(), Some(Ok(Error::AlreadyClaimed)));
+        assert_eq!(client.try_claim_response_payout(&prompt, &3).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(token.balance(&ghosted), 30);
+        assert_eq!(client.get_response_stake(&1).unwrap().balance, 40);
+        
+        // The rest comes back once the job is closed with nothing left Pending
+        assert_eq!(client.try_withdraw_response_stake(&employer, &1).err(), Some(Ok(Error::InvalidTransition)));
+        client.close_job(&employer, &1, &true);
+        assert_eq!(client.withdraw_response_stake(&employer, &1), 40);
+        assert_eq!(token.balance(&employer), 40);
+        assert!(client.get_response_stake(&1).is_none());
+    }
+    
+    #[test]
+    fn test_response_stake_terms_and_first_response() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let prompt = Address::generate(&env);
+        let ghosted = Address::generate(&env);
+        let quitter = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&employer, &100);
+        
+        for _ in 0..2 {
+            client.post_job(
+                &employer,
+                &String::from_str(&env, "Engineer"),
+                &String::from_str(&env, "QmJob"),
+                &salary(&env, 80_000, 80_000),
+                &None,
+                &None,
+                &Vec::new(&env),
+            );
+        }
+        client.close_job(&employer, &2, &false);
+        assert_eq!(
+            client.try_stake_for_response(&employer, &2, &asset.address(), &100, &1_000, &30).err(),
+            Some(Ok(Error::JobInactive))
+        );
+        
+        // Terms are fixed by the first stake
+        client.stake_for_response(&employer, &1, &asset.address(), &70, &1_000, &30);
+        assert_eq!(
+            client.try_stake_for_response(&employer, &1, &asset.address(), &30, &5_000, &30).err(),
+            Some(Ok(Error::InvalidDeposit))
+        );
+        assert_eq!(
+            client.try_stake_for_response(&employer, &1, &asset.address(), &30, &1_000, &1).err(),
+            Some(Ok(Error::InvalidDeposit))
+        );
+        assert_eq!(client.stake_for_response(&employer, &1, &asset.address(), &30, &1_000, &30).balance, 100);
+        
+        client.apply_to_job(&prompt, &1, &cover, &None);
+        client.apply_to_job(&ghosted, &1, &cover, &None);
+        client.apply_to_job(&quitter, &1, &cover, &None);
+        
+        // A prompt first response is not undone by a late rejection, and a withdrawal is never a breach
+        env.ledger().with_mut(|ledger| ledger.timestamp = 10);
+        client.review_application(&employer, &1);
+        client.withdraw_application(&quitter, &3);
+        env.ledger().with_mut(|ledger| ledger.timestamp = 5_010);
+        client.reject_application(&employer, &1, &String::from_str(&env, "Not a fit"));
+        let application = client.get_application(&1).unwrap();
+        assert_eq!(application.first_response_at, Some(10));
+        assert_eq!(application.reviewed_at, Some(5_010));
+        assert_eq!(client.try_claim_response_payout(&prompt, &1).err(), Some(Ok(Error::SlaNotBreached)));
+        assert_eq!(client.try_claim_response_payout(&quitter, &3).err(), Some(Ok(Error::SlaNotBreached)));
+        
+        // Withdrawing the stake leaves the unclaimed payout for the ghosted candidate
+        client.close_job(&employer, &1, &true);
+        assert_eq!(client.withdraw_response_stake(&employer, &1), 70);
+        assert_eq!(client.get_response_stake(&1).unwrap().balance, 30);
+        assert_eq!(client.claim_response_payout(&ghosted, &2), 30);
+        assert_eq!(token.balance(&ghosted), 30);
+        assert_eq!(token.balance(&contract_id), 0);
+    }
+    
+    #[test]
+    fn test_referral_bounty() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let referrer = Address::generate(&env);
+        let hired = Address::generate(&env);
+        let other = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&employer, &100);
+        
+        for _ in 0..2 {
+            client.post_job(
+                &employer,
+                &String::from_str(&env, "Engineer"),
+                &String::from_str(&env, "QmJob"),
+                &salary(&env, 80_000, 80_000),
+                &None,
+                &None,
+                &Vec::new(&env),
+            );
+        }
+        client.fund_referral_bounty(&employer, &1, &asset.address(), &30);
+        client.fund_referral_bounty(&employer, &1, &asset.address(), &20);
+        client.fund_referral_bounty(&employer, &2, &asset.address(), &50);
+        assert_eq!(client.get_referral_bounty(&1).unwrap().amount, 50);
+        assert_eq!(token.balance(&contract_id), 100);
+        
+        assert_eq!(
+            client.try_apply_to_job(&hired, &1, &cover, &Some(hired.clone())).err(),
+            Some(Ok(Error::SelfReferral))
+        );
+        client.apply_to_job(&other, &1, &cover, &None);
+        let application = client.apply_to_job(&hired, &1, &cover, &Some(referrer.clone()));
+        assert_eq!(application.referrer, Some(referrer.clone()));
+        
+        // Hiring the referred candidate pays the referrer
+        client.review_application(&employer, &2);
+        client.accept_application(&employer, &2);
+        assert_eq!(token.balance(&referrer), 50);
+        assert!(client.get_referral_bounty(&1).is_none());
+        
+        // A job closed without a hire refunds the employer
+        client.apply_to_job(&other, &2, &cover, &Some(referrer.clone()));
+        client.close_job(&employer, &2, &false);
+        assert_eq!(client.try_reclaim_referral_bounty(&employer, &2).err(), Some(Ok(Error::InvalidTransition)));
+        client.reject_application(&employer, &3, &String::from_str(&env, "No fit"));
+        assert_eq!(client.reclaim_referral_bounty(&employer, &2), 50);
+        assert_eq!(token.balance(&employer), 50);
+        assert_eq!(token.balance(&contract_id), 0);
+    }
+    
+    #[test]
+    fn test_on_chain_requirements() {
+        use candidate_profile::{CandidateProfileContract, CandidateProfileContractClient};
+        use document_verification::{DocumentVerificationContract, DocumentVerificationContractClient};
+        use soroban_sdk::BytesN;
+        use verifier_registry::{VerifierCategory, VerifierRegistryContract, VerifierRegistryContractClient};
+        
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let employer = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        let degree = String::from_str(&env, "degree");
+        let doc_hash = BytesN::from_array(&env, &[7u8; 32]);
+        
+        env.mock_all_auths();
+        
+        let registry = VerifierRegistryContractClient::new(&env, &env.register_contract(None, VerifierRegistryContract));
+        registry.initialize(&admin);
+        registry.add_verifier(
+            &verifier,
+            &VerifierCategory::University,
+            &String::from_str(&env, "State University"),
+            &String::from_str(&env, "state.edu"),
+            &String::from_str(&env, "QmUniProfile"),
+        );
+        let profiles = CandidateProfileContractClient::new(&env, &env.register_contract(None, CandidateProfileContract));
+        profiles.initialize(&admin, &registry.address);
+        let verifications = DocumentVerificationContractClient::new(&env, &env.register_contract(None, DocumentVerificationContract));
+        verifications.initialize(&admin, &registry.address);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &salary(&env, 80_000, 80_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        let requirements = JobRequirements {
+            require_profile: true,
+            min_verifications: 1,
+            required_doc_types: Vec::from_array(&env, [degree.clone()]),
+        };
+        client.set_job_requirements(&employer, &1, &requirements);
+        assert_eq!(client.get_job_requirements(&1), Some(requirements));
+        
+        // Requirements cannot be checked until the contracts are wired up
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::NotInitialized)));
+        client.initialize(&admin, &profiles.address, &verifications.address);
+        assert_eq!(client.try_initialize(&admin, &profiles.address, &verifications.address).err(), Some(Ok(Error::AlreadyInitialized)));
+        
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::ProfileRequired)));
+        profiles.register_profile(&candidate, &String::from_str(&env, "QmOnChainProfile"), &BytesN::from_array(&env, &[1u8; 32]));
+        
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::InsufficientVerifications)));
+        verifications.verify_document(&verifier, &candidate, &doc_hash, &degree, &true, &String::from_str(&env, "Checked"));
+        
+        assert_eq!(client.try_apply_to_job(&candidate, &1, &cover, &None).err(), Some(Ok(Error::MissingVerifiedDocType)));
+        profiles.add_document(&candidate, &doc_hash, &degree, &String::from_str(&env, "QmDegree"));
+        profiles.verify_document(&verifier, &candidate, &doc_hash);
+        
+        // The profile CID comes from candidate-profile, not the caller
+        let application = client.apply_to_job(&candidate, &1, &cover, &None);
+        assert_eq!(application.candidate_profile_cid, String::from_str(&env, "QmOnChainProfile"));
     }
 }
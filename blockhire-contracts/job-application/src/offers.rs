@@ -0,0 +1,476 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::{Application, ApplicationStatus, Compensation, Error, JobApplicationContract, JobApplicationContractClient};
+
+// Event topic for offer changes
+const OFFER: Symbol = symbol_short!("offer");
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OfferStatus {
+    Issued,
+    Accepted,       // Countersigned by the candidate; the application is Hired
+    Declined,
+    Countered,      // Candidate proposed other terms; the employer re-issues or withdraws
+    Withdrawn,
+    Expired,        // Reported by queries once `expires_at` passes unanswered
+}
+
+// Offer letter issued against an application
+#[contracttype]
+#[derive(Clone)]
+pub struct Offer {
+    pub offer_id: u64,
+    pub application_id: u64,
+    pub employer: Address,
+    pub candidate: Address,
+    pub round: u32,                 // 2 once the application has had a counter-offer
+    pub letter_hash: BytesN<32>,    // SHA-256 of the offer letter on IPFS
+    pub compensation: Compensation,
+    pub expires_at: u64,
+    pub status: OfferStatus,
+    pub created_at: u64,
+    pub responded_at: Option<u64>,
+}
+
+// Candidate's proposed terms in reply to an offer
+#[contracttype]
+#[derive(Clone)]
+pub struct CounterOffer {
+    pub compensation: Compensation,
+    pub note_cid: String,
+    pub timestamp: u64,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer (or organization recruiter) issues an offer; the job's committee quorum and final stage apply as for accept_application.
+    /// Every offer after a counter-offer is round 2, even if the countered one was withdrawn
+    pub fn issue_offer(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+        letter_hash: BytesN<32>,
+        compensation: Compensation,
+        expires_at: u64,
+    ) -> Result<Offer, Error> {
+        employer.require_auth();
+        
+        let application: Application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if !Self::can_receive_offer(&application) {
+            return Err(Error::InvalidTransition);
+        }
+        Self::require_quorum(&env, &application)?;
+        Self::require_final_stage(&env, &application)?;
+        if !compensation.is_valid() {
+            return Err(Error::InvalidCompensation);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidExpiry);
+        }
+        
+        // One open offer at a time; a countered offer is answered by a new one
+        let previous = Self::get_application_offers(env.clone(), application_id).last();
+        if previous.is_some_and(|offer| offer.status == OfferStatus::Issued) {
+            return Err(Error::InvalidTransition);
+        }
+        let round = env.storage().persistent()
+            .get(&(symbol_short!("ofr_rnd"), application_id))
+            .unwrap_or(1);
+        
+        let offer_id = Self::get_next_offer_id(&env);
+        let offer = Offer {
+            offer_id,
+            application_id,
+            employer,
+            candidate: application.candidate,
+            round,
+            letter_hash,
+            compensation,
+            expires_at,
+            status: OfferStatus::Issued,
+            created_at: env.ledger().timestamp(),
+            responded_at: None,
+        };
+        
+        env.storage().persistent().set(&(symbol_short!("ofr"), offer_id), &offer);
+        env.storage().persistent().set(&symbol_short!("ofr_cnt"), &(offer_id + 1));
+        Self::push_index(&env, (symbol_short!("app_ofrs"), application_id), offer_id);
+        
+        Self::publish_offer(&env, symbol_short!("issue"), &offer);
+        Ok(offer)
+    }
+    
+    /// Candidate countersigns an offer, moving the application to Hired
+    pub fn accept_offer(env: Env, candidate: Address, offer_id: u64) -> Result<Offer, Error> {
+        candidate.require_auth();
+        
+        let mut offer = Self::load_open_offer(&env, &candidate, offer_id)?;
+        let mut application: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), offer.application_id))
+            .ok_or(Error::ApplicationNotFound)?;
+        if !Self::can_receive_offer(&application) {
+            return Err(Error::InvalidTransition);
+        }
+        // A committee or stage move since the offer was issued still gates the hire
+        Self::require_quorum(&env, &application)?;
+        Self::require_final_stage(&env, &application)?;
+        
+        Self::respond(&env, symbol_short!("accept"), &mut offer, OfferStatus::Accepted);
+        Self::record_status(&env, &mut application, ApplicationStatus::Hired, &candidate, None);
+        Self::pay_referral_bounty(&env, &application);
+        Ok(offer)
+    }
+    
+    /// Candidate turns an offer down
+    pub fn decline_offer(env: Env, candidate: Address, offer_id: u64) -> Result<Offer, Error> {
+        candidate.require_auth();
+        
+        let mut offer = Self::load_open_offer(&env, &candidate, offer_id)?;
+        Self::respond(&env, symbol_short!("decline"), &mut offer, OfferStatus::Declined);
+        Ok(offer)
+    }
+    
+    /// Candidate proposes other terms; an application gets one counter-offer
+    pub fn counter_offer(
+        env: Env,
+        candidate: Address,
+        offer_id: u64,
+        compensation: Compensation,
+        note_cid: String,
+    ) -> Result<Offer, Error> {
+        candidate.require_auth();
+        
+        let mut offer = Self::load_open_offer(&env, &candidate, offer_id)?;
+        if offer.round > 1 {
+            return Err(Error::InvalidTransition);
+        }
+        if !compensation.is_valid() {
+            return Err(Error::InvalidCompensation);
+        }
+        
+        env.storage().persistent().set(
+            &(symbol_short!("ofr_cntr"), offer_id),
+            &CounterOffer { compensation, note_cid, timestamp: env.ledger().timestamp() },
+        );
+        env.storage().persistent().set(&(symbol_short!("ofr_rnd"), offer.application_id), &(offer.round + 1));
+        Self::respond(&env, symbol_short!("counter"), &mut offer, OfferStatus::Countered);
+        Ok(offer)
+    }
+    
+    /// Employer (or organization recruiter) withdraws an unanswered or countered offer
+    pub fn withdraw_offer(env: Env, employer: Address, offer_id: u64) -> Result<Offer, Error> {
+        employer.require_auth();
+        
+        let mut offer = Self::get_offer(env.clone(), offer_id).ok_or(Error::OfferNotFound)?;
+        Self::load_for_reviewer(&env, &employer, offer.application_id)?;
+        if !matches!(offer.status, OfferStatus::Issued | OfferStatus::Countered) {
+            return Err(Error::InvalidTransition);
+        }
+        
+        Self::respond(&env, symbol_short!("withdraw"), &mut offer, OfferStatus::Withdrawn);
+        Ok(offer)
+    }
+    
+    /// Get offer details; unanswered offers past their expiry report `Expired`
+    pub fn get_offer(env: Env, offer_id: u64) -> Option<Offer> {
+        let mut offer: Offer = env.storage().persistent().get(&(symbol_short!("ofr"), offer_id))?;
+        if offer.status == OfferStatus::Issued && env.ledger().timestamp() > offer.expires_at {
+            offer.status = OfferStatus::Expired;
+        }
+        Some(offer)
+    }
+    
+    /// Get the candidate's counter-proposal to an offer
+    pub fn get_counter_offer(env: Env, offer_id: u64) -> Option<CounterOffer> {
+        env.storage().persistent().get(&(symbol_short!("ofr_cntr"), offer_id))
+    }
+    
+    /// Get every offer made on an application, oldest first
+    pub fn get_application_offers(env: Env, application_id: u64) -> Vec<Offer> {
+        let mut offers = Vec::new(&env);
+        for offer_id in Self::get_index(&env, (symbol_short!("app_ofrs"), application_id)).iter() {
+            if let Some(offer) = Self::get_offer(env.clone(), offer_id) {
+                offers.push_back(offer);
+            }
+        }
+        offers
+    }
+    
+    // Helper functions
+    pub(crate) fn has_open_offer(env: &Env, application_id: u64) -> bool {
+        Self::get_application_offers(env.clone(), application_id)
+            .last()
+            .is_some_and(|offer| matches!(offer.status, OfferStatus::Issued | OfferStatus::Countered))
+    }
+    
+    fn can_receive_offer(application: &Application) -> bool {
+        matches!(
+            application.status,
+            ApplicationStatus::UnderReview | ApplicationStatus::Verified | ApplicationStatus::Accepted
+        )
+    }
+    
+    fn load_open_offer(env: &Env, candidate: &Address, offer_id: u64) -> Result<Offer, Error> {
+        let offer = Self::get_offer(env.clone(), offer_id).ok_or(Error::OfferNotFound)?;
+        if offer.candidate != *candidate {
+            return Err(Error::NotAuthorized);
+        }
+        match offer.status {
+            OfferStatus::Issued => Ok(offer),
+            OfferStatus::Expired => Err(Error::OfferExpired),
+            _ => Err(Error::InvalidTransition),
+        }
+    }
+    
+    fn respond(env: &Env, action: Symbol, offer: &mut Offer, status: OfferStatus) {
+        offer.status = status;
+        offer.responded_at = Some(env.ledger().timestamp());
+        env.storage().persistent().set(&(symbol_short!("ofr"), offer.offer_id), offer);
+        Self::publish_offer(env, action, offer);
+    }
+    
+    fn get_next_offer_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("ofr_cnt"))
+            .unwrap_or(1)
+    }
+    
+    fn publish_offer(env: &Env, action: Symbol, offer: &Offer) {
+        env.events().publish(
+            (OFFER, action, offer.application_id),
+            (offer.offer_id, offer.status.clone()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Currency, PayPeriod};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token};
+    
+    fn yearly(env: &Env, min: i128, max: i128) -> Compensation {
+        Compensation {
+            min,
+            max,
+            currency: Currency::Iso(String::from_str(env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        }
+    }
+    
+    #[test]
+    fn test_offer_lifecycle() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let hired = Address::generate(&env);
+        let other = Address::generate(&env);
+        let letter = BytesN::from_array(&env, &[9u8; 32]);
+        let cover = String::from_str(&env, "QmCover");
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &yearly(&env, 90_000, 110_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&hired, &1, &cover, &None);
+        client.apply_to_job(&other, &1, &cover, &None);
+        
+        // Offers follow review
+        assert_eq!(
+            client.try_issue_offer(&employer, &1, &letter, &yearly(&env, 100_000, 100_000), &5_000).err(),
+            Some(Ok(Error::InvalidTransition))
+        );
+        client.review_application(&employer, &1);
+        assert_eq!(
+            client.try_issue_offer(&employer, &1, &letter, &yearly(&env, 100_000, 100_000), &1_000).err(),
+            Some(Ok(Error::InvalidExpiry))
+        );
+        client.issue_offer(&employer, &1, &letter, &yearly(&env, 100_000, 100_000), &5_000);
+        assert_eq!(
+            client.try_issue_offer(&employer, &1, &letter, &yearly(&env, 100_000, 100_000), &5_000).err(),
+            Some(Ok(Error::InvalidTransition))
+        );
+        
+        // Counter-offer round: the employer re-issues on the candidate's terms
+        let note = String::from_str(&env, "QmCounter");
+        assert_eq!(
+            client.try_counter_offer(&other, &1, &yearly(&env, 110_000, 110_000), &note).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        let countered = client.counter_offer(&hired, &1, &yearly(&env, 110_000, 110_000), &note);
+        assert_eq!(countered.status, OfferStatus::Countered);
+        assert_eq!(client.get_counter_offer(&1).unwrap().compensation.min, 110_000);
+        
+        let revised = client.issue_offer(&employer, &1, &letter, &yearly(&env, 110_000, 110_000), &5_000);
+        assert_eq!(revised.round, 2);
+        assert_eq!(
+            client.try_counter_offer(&hired, &2, &yearly(&env, 120_000, 120_000), &note).err(),
+            Some(Ok(Error::InvalidTransition))
+        );
+        
+        // Accepting an application is not a hire; the countersignature is
+        client.accept_application(&employer, &1);
+        assert_eq!(client.get_application(&1).unwrap().status, ApplicationStatus::Accepted);
+        let accepted = client.accept_offer(&hired, &2);
+        assert_eq!(accepted.status, OfferStatus::Accepted);
+        assert_eq!(client.get_application(&1).unwrap().status, ApplicationStatus::Hired);
+        assert_eq!(client.get_application_history(&1).last().unwrap().actor, hired);
+        
+        // Unanswered offers expire
+        client.review_application(&employer, &2);
+        client.issue_offer(&employer, &2, &letter, &yearly(&env, 100_000, 100_000), &2_000);
+        env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+        assert_eq!(client.get_offer(&3).unwrap().status, OfferStatus::Expired);
+        assert_eq!(client.try_accept_offer(&other, &3).err(), Some(Ok(Error::OfferExpired)));
+        assert_eq!(client.get_application(&2).unwrap().status, ApplicationStatus::UnderReview);
+        
+        // An expired offer can be replaced, then declined
+        client.issue_offer(&employer, &2, &letter, &yearly(&env, 105_000, 105_000), &9_000);
+        assert_eq!(client.decline_offer(&other, &4).status, OfferStatus::Declined);
+        assert_eq!(client.get_application_offers(&2).len(), 2);
+        assert_eq!(client.try_withdraw_offer(&employer, &4).err(), Some(Ok(Error::InvalidTransition)));
+    }
+    
+    #[test]
+    fn test_offers_respect_committee_and_stages() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let member = Address::generate(&env);
+        let letter = BytesN::from_array(&env, &[9u8; 32]);
+        let terms = yearly(&env, 100_000, 100_000);
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &terms,
+            &None,
+            &None,
+            &Vec::from_array(&env, [String::from_str(&env, "Screen"), String::from_str(&env, "Onsite")]),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        client.set_hiring_committee(&employer, &1, &Vec::from_array(&env, [member.clone()]), &1);
+        
+        // An offer is an accept in all but name, so it waits for the last stage and the quorum
+        client.review_application(&employer, &1);
+        assert_eq!(
+            client.try_issue_offer(&employer, &1, &letter, &terms, &5_000).err(),
+            Some(Ok(Error::QuorumNotMet))
+        );
+        client.cast_committee_vote(&member, &1, &crate::Vote::Approve, &None);
+        assert_eq!(
+            client.try_issue_offer(&employer, &1, &letter, &terms, &5_000).err(),
+            Some(Ok(Error::PipelineIncomplete))
+        );
+        client.move_to_stage(&employer, &1, &1);
+        client.issue_offer(&employer, &1, &letter, &terms, &5_000);
+        
+        // Moving back after the offer blocks the countersignature
+        client.move_to_stage(&employer, &1, &0);
+        assert_eq!(client.try_accept_offer(&candidate, &1).err(), Some(Ok(Error::PipelineIncomplete)));
+        client.move_to_stage(&employer, &1, &1);
+        client.accept_offer(&candidate, &1);
+    }
+    
+    #[test]
+    fn test_hire_through_offer_pays_referral_bounty() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let referrer = Address::generate(&env);
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        token::StellarAssetClient::new(&env, &asset.address()).mint(&employer, &50);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &yearly(&env, 90_000, 110_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.fund_referral_bounty(&employer, &1, &asset.address(), &50);
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &Some(referrer.clone()));
+        client.review_application(&employer, &1);
+        client.issue_offer(&employer, &1, &BytesN::from_array(&env, &[9u8; 32]), &yearly(&env, 100_000, 100_000), &5_000);
+        client.accept_offer(&candidate, &1);
+        
+        assert_eq!(client.get_application(&1).unwrap().status, ApplicationStatus::Hired);
+        assert_eq!(token.balance(&referrer), 50);
+        assert!(client.get_referral_bounty(&1).is_none());
+    }
+    
+    #[test]
+    fn test_offer_rounds_span_the_application() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let letter = BytesN::from_array(&env, &[9u8; 32]);
+        let note = String::from_str(&env, "QmCounter");
+        
+        env.mock_all_auths();
+        env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &yearly(&env, 90_000, 110_000),
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        client.review_application(&employer, &1);
+        client.issue_offer(&employer, &1, &letter, &yearly(&env, 100_000, 100_000), &5_000);
+        client.counter_offer(&candidate, &1, &yearly(&env, 110_000, 110_000), &note);
+        
+        // Withdrawing the countered offer does not buy the candidate a second counter
+        client.withdraw_offer(&employer, &1);
+        let reissued = client.issue_offer(&employer, &1, &letter, &yearly(&env, 105_000, 105_000), &5_000);
+        assert_eq!(reissued.round, 2);
+        assert_eq!(
+            client.try_counter_offer(&candidate, &2, &yearly(&env, 110_000, 110_000), &note).err(),
+            Some(Ok(Error::InvalidTransition))
+        );
+        
+        // An accepted application cannot be reopened under an outstanding offer
+        client.accept_application(&employer, &1);
+        let reason = String::from_str(&env, "Reconsider");
+        assert_eq!(client.try_reopen_application(&employer, &1, &reason).err(), Some(Ok(Error::InvalidTransition)));
+        client.withdraw_offer(&employer, &2);
+        assert_eq!(client.reopen_application(&employer, &1, &reason).status, ApplicationStatus::UnderReview);
+    }
+}
@@ -0,0 +1,398 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::{Compensation, Error, JobApplicationContract, JobApplicationContractClient, JobPage, JobPosting};
+
+// Event topic for organization changes
+const ORG: Symbol = symbol_short!("org");
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrgRole {
+    Owner,          // Exactly one; manages admins and transfers ownership
+    Admin,          // Manages recruiters and viewers
+    Recruiter,      // Posts jobs, reviews and decides applications, closes jobs
+    Viewer,         // Read-only seat for dashboards
+}
+
+impl OrgRole {
+    pub fn can_manage_applications(&self) -> bool {
+        !matches!(self, OrgRole::Viewer)
+    }
+    
+    // Owners manage everyone below them; admins only recruiters and viewers
+    pub fn can_assign(&self, role: &OrgRole) -> bool {
+        match self {
+            OrgRole::Owner => *role != OrgRole::Owner,
+            OrgRole::Admin => matches!(role, OrgRole::Recruiter | OrgRole::Viewer),
+            _ => false,
+        }
+    }
+}
+
+// Employer organization owning jobs on behalf of its members
+#[contracttype]
+#[derive(Clone)]
+pub struct Organization {
+    pub org_id: u64,
+    pub name: String,
+    pub owner: Address,
+    pub created_at: u64,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Create an organization; the caller becomes its owner
+    pub fn create_organization(env: Env, owner: Address, name: String) -> Result<Organization, Error> {
+        owner.require_auth();
+        
+        let org_id = Self::get_next_org_id(&env);
+        let organization = Organization {
+            org_id,
+            name,
+            owner: owner.clone(),
+            created_at: env.ledger().timestamp(),
+        };
+        
+        env.storage().persistent().set(&(symbol_short!("org"), org_id), &organization);
+        env.storage().persistent().set(&symbol_short!("org_cnt"), &(org_id + 1));
+        Self::store_member(&env, org_id, &owner, OrgRole::Owner);
+        
+        env.events().publish((ORG, symbol_short!("create"), owner), org_id);
+        Ok(organization)
+    }
+    
+    /// Owner or admin adds a member or changes their role
+    pub fn set_member_role(
+        env: Env,
+        actor: Address,
+        org_id: u64,
+        member: Address,
+        role: OrgRole,
+    ) -> Result<(), Error> {
+        actor.require_auth();
+        
+        let actor_role = Self::require_member(&env, org_id, &actor)?;
+        let current = Self::get_member_role(env.clone(), org_id, member.clone());
+        if !actor_role.can_assign(&role) || current.is_some_and(|current| !actor_role.can_assign(&current)) {
+            return Err(Error::NotAuthorized);
+        }
+        
+        Self::store_member(&env, org_id, &member, role);
+        env.events().publish((ORG, symbol_short!("role"), org_id), (member, role));
+        Ok(())
+    }
+    
+    /// Owner or admin removes a member below them
+    pub fn remove_member(env: Env, actor: Address, org_id: u64, member: Address) -> Result<(), Error> {
+        actor.require_auth();
+        
+        let actor_role = Self::require_member(&env, org_id, &actor)?;
+        let current = Self::get_member_role(env.clone(), org_id, member.clone()).ok_or(Error::NotAuthorized)?;
+        if !actor_role.can_assign(&current) {
+            return Err(Error::NotAuthorized);
+        }
+        
+        env.storage().persistent().remove(&(symbol_short!("org_mbr"), org_id, member.clone()));
+        let key = (symbol_short!("org_mbrs"), org_id);
+        let mut members: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(&env));
+        if let Some(index) = members.first_index_of(&member) {
+            members.remove(index);
+        }
+        env.storage().persistent().set(&key, &members);
+        
+        env.events().publish((ORG, symbol_short!("remove"), org_id), member);
+        Ok(())
+    }
+    
+    /// Owner hands the organization to another address; the previous owner stays on as admin
+    pub fn transfer_organization(
+        env: Env,
+        owner: Address,
+        org_id: u64,
+        new_owner: Address,
+    ) -> Result<Organization, Error> {
+        owner.require_auth();
+        
+        let key = (symbol_short!("org"), org_id);
+        let mut organization: Organization = env.storage().persistent()
+            .get(&key)
+            .ok_or(Error::OrganizationNotFound)?;
+        if organization.owner != owner {
+            return Err(Error::NotAuthorized);
+        }
+        
+        organization.owner = new_owner.clone();
+        env.storage().persistent().set(&key, &organization);
+        Self::store_member(&env, org_id, &owner, OrgRole::Admin);
+        Self::store_member(&env, org_id, &new_owner, OrgRole::Owner);
+        
+        env.events().publish((ORG, symbol_short!("owner"), org_id), new_owner);
+        Ok(organization)
+    }
+    
+    /// Recruiter, admin or owner posts a job owned by the organization
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_organization_job(
+        env: Env,
+        member: Address,
+        org_id: u64,
+        title: String,
+        ipfs_details: String,
+        compensation: Compensation,
+        deadline: Option<u64>,
+        max_applications: Option<u32>,
+        stages: Vec<String>,
+    ) -> Result<JobPosting, Error> {
+        member.require_auth();
+        
+        if !Self::require_member(&env, org_id, &member)?.can_manage_applications() {
+            return Err(Error::NotAuthorized);
+        }
+        Self::create_job(
+            &env,
+            member,
+            Some(org_id),
+            title,
+            ipfs_details,
+            compensation,
+            deadline,
+            max_applications,
+            stages,
+        )
+    }
+    
+    /// Get organization details
+    pub fn get_organization(env: Env, org_id: u64) -> Option<Organization> {
+        env.storage().persistent().get(&(symbol_short!("org"), org_id))
+    }
+    
+    /// Get a member's role in an organization
+    pub fn get_member_role(env: Env, org_id: u64, member: Address) -> Option<OrgRole> {
+        env.storage().persistent().get(&(symbol_short!("org_mbr"), org_id, member))
+    }
+    
+    /// Get every member of an organization, in joining order
+    pub fn get_organization_members(env: Env, org_id: u64) -> Vec<Address> {
+        env.storage().persistent()
+            .get(&(symbol_short!("org_mbrs"), org_id))
+            .unwrap_or(Vec::new(&env))
+    }
+    
+    /// List an organization's jobs (active or closed), oldest first
+    pub fn get_organization_jobs(env: Env, org_id: u64, cursor: u64, limit: u32) -> JobPage {
+        let ids = Self::get_index(&env, (symbol_short!("org_jobs"), org_id));
+        let (jobs, next_cursor) = Self::collect_page(&env, &ids, cursor, limit, |job_id| {
+            Self::get_job(env.clone(), job_id)
+        });
+        JobPage { jobs, next_cursor }
+    }
+    
+    // Helper functions
+    fn require_member(env: &Env, org_id: u64, member: &Address) -> Result<OrgRole, Error> {
+        if !env.storage().persistent().has(&(symbol_short!("org"), org_id)) {
+            return Err(Error::OrganizationNotFound);
+        }
+        Self::get_member_role(env.clone(), org_id, member.clone()).ok_or(Error::NotAuthorized)
+    }
+    
+    fn store_member(env: &Env, org_id: u64, member: &Address, role: OrgRole) {
+        let key = (symbol_short!("org_mbrs"), org_id);
+        let mut members: Vec<Address> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+        if !members.contains(member) {
+            members.push_back(member.clone());
+            env.storage().persistent().set(&key, &members);
+        }
+        env.storage().persistent().set(&(symbol_short!("org_mbr"), org_id, member.clone()), &role);
+    }
+    
+    fn get_next_org_id(env: &Env) -> u64 {
+        env.storage().persistent()
+            .get(&symbol_short!("org_cnt"))
+            .unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ApplicationStatus, Currency, PayPeriod};
+    use soroban_sdk::{testutils::Address as _, token};
+    
+    #[test]
+    fn test_organization_roles() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let admin = Address::generate(&env);
+        let recruiter = Address::generate(&env);
+        let viewer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let compensation = Compensation {
+            min: 80_000,
+            max: 80_000,
+            currency: Currency::Iso(String::from_str(&env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        };
+        
+        env.mock_all_auths();
+        
+        let organization = client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &admin, &OrgRole::Admin);
+        client.set_member_role(&admin, &1, &recruiter, &OrgRole::Recruiter);
+        client.set_member_role(&admin, &1, &viewer, &OrgRole::Viewer);
+        assert_eq!(organization.owner, owner);
+        assert_eq!(client.get_organization_members(&1).len(), 4);
+        
+        // Admins cannot touch other admins or create owners
+        assert_eq!(client.try_set_member_role(&admin, &1, &outsider, &OrgRole::Admin).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_set_member_role(&owner, &1, &outsider, &OrgRole::Owner).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_remove_member(&admin, &1, &owner).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_set_member_role(&owner, &2, &outsider, &OrgRole::Viewer).err(), Some(Ok(Error::OrganizationNotFound)));
+        
+        // Viewers cannot post; recruiters post on the organization's behalf
+        let title = String::from_str(&env, "Engineer");
+        let details = String::from_str(&env, "QmJob");
+        assert_eq!(
+            client.try_post_organization_job(&viewer, &1, &title, &details, &compensation, &None, &None, &Vec::new(&env)).err(),
+            Some(Ok(Error::NotAuthorized))
+        );
+        let job = client.post_organization_job(&recruiter, &1, &title, &details, &compensation, &None, &None, &Vec::new(&env));
+        assert_eq!(job.organization, Some(1));
+        assert_eq!(client.get_organization_jobs(&1, &0, &10).jobs.len(), 1);
+        
+        // Any recruiting member can move the application; the history names who acted
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        assert_eq!(client.try_review_application(&viewer, &1).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_review_application(&outsider, &1).err(), Some(Ok(Error::NotAuthorized)));
+        client.review_application(&admin, &1);
+        client.verify_application(&owner, &1, &String::from_str(&env, "Docs check out"));
+        client.accept_application(&recruiter, &1);
+        let history = client.get_application_history(&1);
+        assert_eq!(history.get(1).unwrap().actor, admin);
+        assert_eq!(history.get(2).unwrap().actor, owner);
+        assert_eq!(history.get(3).unwrap().actor, recruiter);
+        assert_eq!(client.get_application(&1).unwrap().status, ApplicationStatus::Accepted);
+        
+        // Removed members lose access; ownership transfers keep the old owner as admin
+        client.remove_member(&admin, &1, &recruiter);
+        assert_eq!(client.get_member_role(&1, &recruiter), None);
+        assert_eq!(client.try_close_job(&recruiter, &1, &false).err(), Some(Ok(Error::NotAuthorized)));
+        client.transfer_organization(&owner, &1, &admin);
+        assert_eq!(client.get_member_role(&1, &owner), Some(OrgRole::Admin));
+        assert!(!client.close_job(&owner, &1, &false).is_active);
+    }
+    
+    #[test]
+    fn test_job_management_follows_membership() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let admin = Address::generate(&env);
+        let recruiter = Address::generate(&env);
+        let title = String::from_str(&env, "Senior Engineer");
+        let details = String::from_str(&env, "QmJobV2");
+        let compensation = Compensation {
+            min: 90_000,
+            max: 90_000,
+            currency: Currency::Iso(String::from_str(&env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        };
+        
+        env.mock_all_auths();
+        
+        client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &admin, &OrgRole::Admin);
+        client.set_member_role(&admin, &1, &recruiter, &OrgRole::Recruiter);
+        client.post_organization_job(
+            &recruiter,
+            &1,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &compensation,
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&Address::generate(&env), &1, &String::from_str(&env, "QmCover"), &None);
+        
+        // The poster loses every management path once removed; the owner keeps them
+        client.remove_member(&admin, &1, &recruiter);
+        assert_eq!(client.try_update_job(&recruiter, &1, &title, &details, &compensation).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_flag_spam(&recruiter, &1).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_set_reapply_cooldown(&recruiter, &1, &Some(60)).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.update_job(&owner, &1, &title, &details, &compensation).revision, 2);
+        client.set_reapply_cooldown(&admin, &1, &Some(60));
+        client.flag_spam(&owner, &1);
+    }
+    
+    #[test]
+    fn test_escrow_returns_to_its_funder() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let recruiter = Address::generate(&env);
+        let other = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &asset.address());
+        let minter = token::StellarAssetClient::new(&env, &asset.address());
+        minter.mint(&recruiter, &150);
+        minter.mint(&other, &10);
+        minter.mint(&candidate, &5);
+        
+        client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &recruiter, &OrgRole::Recruiter);
+        client.set_member_role(&owner, &1, &other, &OrgRole::Recruiter);
+        client.post_organization_job(
+            &recruiter,
+            &1,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 90_000,
+                max: 90_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        
+        // Escrow belongs to the recruiter who funded it, so colleagues cannot top it up
+        client.fund_referral_bounty(&recruiter, &1, &asset.address(), &50);
+        client.stake_for_response(&recruiter, &1, &asset.address(), &100, &60, &20);
+        assert_eq!(client.try_fund_referral_bounty(&other, &1, &asset.address(), &10).err(), Some(Ok(Error::InvalidDeposit)));
+        assert_eq!(
+            client.try_stake_for_response(&other, &1, &asset.address(), &10, &60, &20).err(),
+            Some(Ok(Error::InvalidDeposit))
+        );
+        
+        // A forfeited spam deposit is the only thing the organization's owner collects
+        client.set_application_deposit(&recruiter, &1, &asset.address(), &5);
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        client.flag_spam(&other, &1);
+        assert_eq!(token.balance(&owner), 5);
+        
+        // Closing refunds the bounty and the stake to the recruiter, whoever closes the job
+        client.close_job(&other, &1, &false);
+        assert_eq!(client.withdraw_response_stake(&other, &1), 100);
+        assert_eq!(token.balance(&recruiter), 150);
+        assert_eq!(token.balance(&other), 10);
+        assert_eq!(token.balance(&owner), 5);
+    }
+}
@@ -0,0 +1,337 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::{Application, ApplicationStatus, Error, JobApplicationContract, JobApplicationContractClient, JobPosting};
+
+// Event topic for rating changes
+const RATING: Symbol = symbol_short!("rating");
+
+// Scores kept in a reputation's recent window
+const RECENT_WINDOW: u32 = 10;
+
+// One party's rating of the other after a hire
+#[contracttype]
+#[derive(Clone)]
+pub struct Rating {
+    pub application_id: u64,
+    pub rater: Address,
+    pub ratee: Address,
+    pub organization: Option<u64>,  // Set when the employer side is an organization, which earns the score
+    pub score: u32,                 // 1-5
+    pub review_cid: String,         // Written review on IPFS
+    pub timestamp: u64,
+}
+
+// Aggregate of every rating an address has received; averages are in hundredths (450 = 4.50)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reputation {
+    pub count: u32,
+    pub total_score: u64,
+    pub average: u32,
+    pub recent: Vec<u32>,           // Latest scores, oldest first
+    pub recent_average: u32,
+    pub updated_at: u64,
+}
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer or candidate of an accepted application rates the other party, once per side. On organization
+    /// jobs any current recruiter rates for the employer side and the candidate's score goes to the organization
+    pub fn rate_counterparty(
+        env: Env,
+        rater: Address,
+        application_id: u64,
+        score: u32,
+        review_cid: String,
+    ) -> Result<Rating, Error> {
+        rater.require_auth();
+        
+        let application: Application = env.storage().persistent()
+            .get(&(symbol_short!("app"), application_id))
+            .ok_or(Error::ApplicationNotFound)?;
+        let job: JobPosting = env.storage().persistent()
+            .get(&(symbol_short!("job"), application.job_id))
+            .ok_or(Error::JobNotFound)?;
+        
+        // Employer-side ratings are keyed by the posting address so one recruiter rates for everyone
+        let (side, ratee) = if rater == application.candidate {
+            (rater.clone(), application.employer.clone())
+        } else if Self::can_manage_job(&env, &rater, &job) {
+            (application.employer.clone(), application.candidate.clone())
+        } else {
+            return Err(Error::NotAuthorized);
+        };
+        // Also covers a candidate who joined the organization after applying
+        if Self::is_job_insider(&env, &application.candidate, &job) {
+            return Err(Error::SelfApplication);
+        }
+        if !matches!(application.status, ApplicationStatus::Accepted | ApplicationStatus::Hired) {
+            return Err(Error::InvalidTransition);
+        }
+        if !(1..=5).contains(&score) {
+            return Err(Error::InvalidScore);
+        }
+        
+        let rating_key = (symbol_short!("rating"), application_id, side);
+        if env.storage().persistent().has(&rating_key) {
+            return Err(Error::AlreadyRated);
+        }
+        
+        let rating = Rating {
+            application_id,
+            rater: rater.clone(),
+            ratee: ratee.clone(),
+            organization: job.organization.filter(|_| rater == application.candidate),
+            score,
+            review_cid,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&rating_key, &rating);
+        
+        match rating.organization {
+            Some(org_id) => {
+                let reputation = Self::add_score(Self::get_organization_reputation(env.clone(), org_id), &rating);
+                env.storage().persistent().set(&(symbol_short!("org_rep"), org_id), &reputation);
+            }
+            None => {
+                let reputation = Self::add_score(Self::get_reputation(env.clone(), ratee.clone()), &rating);
+                env.storage().persistent().set(&(symbol_short!("rep"), ratee.clone()), &reputation);
+            }
+        }
+        
+        env.events().publish((RATING, symbol_short!("submit"), ratee), (application_id, rater, score));
+        Ok(rating)
+    }
+    
+    /// Get the rating a party left on an application; the employer side is looked up by the application's employer
+    pub fn get_rating(env: Env, application_id: u64, rater: Address) -> Option<Rating> {
+        env.storage().persistent().get(&(symbol_short!("rating"), application_id, rater))
+    }
+    
+    /// Get the reputation an employer or candidate has earned; empty if never rated
+    pub fn get_reputation(env: Env, address: Address) -> Reputation {
+        env.storage().persistent()
+            .get(&(symbol_short!("rep"), address))
+            .unwrap_or(Self::empty_reputation(&env))
+    }
+    
+    /// Get the reputation an organization has earned from candidates hired into its jobs
+    pub fn get_organization_reputation(env: Env, org_id: u64) -> Reputation {
+        env.storage().persistent()
+            .get(&(symbol_short!("org_rep"), org_id))
+            .unwrap_or(Self::empty_reputation(&env))
+    }
+    
+    // Helper functions
+    pub(crate) fn is_rated(env: &Env, application: &Application) -> bool {
+        let ratings = env.storage().persistent();
+        ratings.has(&(symbol_short!("rating"), application.application_id, application.candidate.clone()))
+            || ratings.has(&(symbol_short!("rating"), application.application_id, application.employer.clone()))
+    }
+    
+    fn add_score(mut reputation: Reputation, rating: &Rating) -> Reputation {
+        reputation.count += 1;
+        reputation.total_score += u64::from(rating.score);
+        reputation.average = (reputation.total_score * 100 / u64::from(reputation.count)) as u32;
+        reputation.recent.push_back(rating.score);
+        if reputation.recent.len() > RECENT_WINDOW {
+            reputation.recent.pop_front();
+        }
+        reputation.recent_average = reputation.recent.iter().sum::<u32>() * 100 / reputation.recent.len();
+        reputation.updated_at = rating.timestamp;
+        reputation
+    }
+    
+    fn empty_reputation(env: &Env) -> Reputation {
+        Reputation {
+            count: 0,
+            total_score: 0,
+            average: 0,
+            recent: Vec::new(env),
+            recent_average: 0,
+            updated_at: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compensation, Currency, PayPeriod};
+    use soroban_sdk::testutils::Address as _;
+    
+    #[test]
+    fn test_two_way_ratings() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let review = String::from_str(&env, "QmReview");
+        
+        env.mock_all_auths();
+        
+        client.post_job(
+            &employer,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 80_000,
+                max: 80_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        
+        let mut candidates = Vec::new(&env);
+        for _ in 0..12 {
+            let candidate = Address::generate(&env);
+            let application = client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+            client.review_application(&employer, &application.application_id);
+            candidates.push_back(candidate);
+        }
+        
+        // Only parties to an accepted application may rate
+        let first = candidates.get(0).unwrap();
+        assert_eq!(client.try_rate_counterparty(&first, &1, &5, &review).err(), Some(Ok(Error::InvalidTransition)));
+        client.accept_application(&employer, &1);
+        assert_eq!(client.try_rate_counterparty(&outsider, &1, &5, &review).err(), Some(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_rate_counterparty(&first, &1, &6, &review).err(), Some(Ok(Error::InvalidScore)));
+        
+        client.rate_counterparty(&first, &1, &4, &review);
+        client.rate_counterparty(&employer, &1, &5, &review);
+        assert_eq!(client.try_rate_counterparty(&first, &1, &1, &review).err(), Some(Ok(Error::AlreadyRated)));
+        assert_eq!(client.get_rating(&1, &employer).unwrap().ratee, first);
+        assert_eq!(client.get_reputation(&first).average, 500);
+        
+        // Eleven more hires: two 1s followed by nine 5s push the 4 and a 1 out of the window
+        for application_id in 2..=12u64 {
+            let candidate = candidates.get(application_id as u32 - 1).unwrap();
+            client.accept_application(&employer, &application_id);
+            let score = if application_id <= 3 { 1 } else { 5 };
+            client.rate_counterparty(&candidate, &application_id, &score, &review);
+        }
+        
+        let reputation = client.get_reputation(&employer);
+        assert_eq!(reputation.count, 12);
+        assert_eq!(reputation.total_score, 4 + 2 + 45);
+        assert_eq!(reputation.average, 425);
+        assert_eq!(reputation.recent.len(), 10);
+        assert_eq!(reputation.recent_average, 460);
+        assert_eq!(client.get_reputation(&outsider).count, 0);
+    }
+    
+    #[test]
+    fn test_organization_earns_employer_reputation() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let recruiter = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let review = String::from_str(&env, "QmReview");
+        
+        env.mock_all_auths();
+        
+        client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &recruiter, &crate::OrgRole::Recruiter);
+        client.post_organization_job(
+            &recruiter,
+            &1,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &Compensation {
+                min: 80_000,
+                max: 80_000,
+                currency: Currency::Iso(String::from_str(&env, "USD")),
+                period: PayPeriod::Yearly,
+                equity: false,
+            },
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        client.apply_to_job(&candidate, &1, &String::from_str(&env, "QmCover"), &None);
+        client.review_application(&owner, &1);
+        client.accept_application(&owner, &1);
+        
+        let rating = client.rate_counterparty(&candidate, &1, &4, &review);
+        assert_eq!(rating.organization, Some(1));
+        assert_eq!(client.get_organization_reputation(&1).average, 400);
+        assert_eq!(client.get_reputation(&recruiter).count, 0);
+        
+        // One rating for the whole employer side, from a current member
+        client.rate_counterparty(&owner, &1, &5, &review);
+        assert_eq!(client.try_rate_counterparty(&recruiter, &1, &5, &review).err(), Some(Ok(Error::AlreadyRated)));
+        assert_eq!(client.get_reputation(&candidate).average, 500);
+    }
+    
+    #[test]
+    fn test_insiders_cannot_apply_or_rate_themselves() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let owner = Address::generate(&env);
+        let viewer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        let review = String::from_str(&env, "QmReview");
+        let pay = Compensation {
+            min: 80_000,
+            max: 80_000,
+            currency: Currency::Iso(String::from_str(&env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        };
+        
+        env.mock_all_auths();
+        
+        // Neither the poster nor any organization seat can apply
+        client.post_job(
+            &owner,
+            &String::from_str(&env, "Engineer"),
+            &String::from_str(&env, "QmJob"),
+            &pay,
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        assert_eq!(client.try_apply_to_job(&owner, &1, &cover, &None).err(), Some(Ok(Error::SelfApplication)));
+        client.create_organization(&owner, &String::from_str(&env, "Acme"));
+        client.set_member_role(&owner, &1, &viewer, &crate::OrgRole::Viewer);
+        client.post_organization_job(
+            &owner,
+            &1,
+            &String::from_str(&env, "Designer"),
+            &String::from_str(&env, "QmJob"),
+            &pay,
+            &None,
+            &None,
+            &Vec::new(&env),
+        );
+        assert_eq!(client.try_apply_to_job(&viewer, &2, &cover, &None).err(), Some(Ok(Error::SelfApplication)));
+        
+        // Joining after applying does not open a route to rating yourself
+        client.apply_to_job(&candidate, &2, &cover, &None);
+        client.review_application(&owner, &1);
+        client.accept_application(&owner, &1);
+        client.set_member_role(&owner, &1, &candidate, &crate::OrgRole::Viewer);
+        assert_eq!(client.try_rate_counterparty(&candidate, &1, &5, &review).err(), Some(Ok(Error::SelfApplication)));
+        client.remove_member(&owner, &1, &candidate);
+        
+        // A rated application cannot be reopened and accepted for a second round of ratings
+        client.rate_counterparty(&candidate, &1, &5, &review);
+        assert_eq!(
+            client.try_reopen_application(&owner, &1, &String::from_str(&env, "Again")).err(),
+            Some(Ok(Error::AlreadyRated))
+        );
+    }
+}
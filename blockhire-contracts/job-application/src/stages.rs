@@ -0,0 +1,167 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, String, Vec};
+
+use crate::{
+    Application, ApplicationStatus, Error, JobApplicationContract, JobApplicationContractClient, JobPosting,
+    APPLICATION,
+};
+
+// Most custom stages a single job may define
+const MAX_STAGES: u32 = 20;
+
+#[contractimpl]
+impl JobApplicationContract {
+    
+    /// Employer (or organization recruiter) moves an open application to another stage of the job's
+    /// pipeline: one step forward, or back to any earlier stage. Pending applications enter UnderReview.
+    pub fn move_to_stage(
+        env: Env,
+        employer: Address,
+        application_id: u64,
+        stage: u32,
+    ) -> Result<Application, Error> {
+        employer.require_auth();
+        
+        let mut application = Self::load_for_reviewer(&env, &employer, application_id)?;
+        if application.status.is_terminal() {
+            return Err(Error::InvalidTransition);
+        }
+        
+        let job: JobPosting = env.storage().persistent()
+            .get(&(symbol_short!("job"), application.job_id))
+            .ok_or(Error::JobNotFound)?;
+        let current = application.stage.ok_or(Error::InvalidStage)?;
+        if stage >= job.stages.len() || stage == current || stage > current + 1 {
+            return Err(Error::InvalidStage);
+        }
+        
+        // The history entry carries the stage name as its note
+        application.stage = Some(stage);
+        let stage_name = job.stages.get(stage);
+        if application.status == ApplicationStatus::Pending {
+            Self::transition(&env, &mut application, ApplicationStatus::UnderReview, &employer, stage_name)?;
+        } else {
+            env.storage().persistent().set(&(symbol_short!("app"), application_id), &application);
+            Self::push_history(&env, application_id, &application.status, &employer, stage_name);
+        }
+        
+        env.events().publish(
+            (APPLICATION, symbol_short!("stage"), application.job_id, application.candidate.clone()),
+            (application_id, stage),
+        );
+        Ok(application)
+    }
+    
+    /// Get the name of the pipeline stage an application is in
+    pub fn get_application_stage(env: Env, application_id: u64) -> Option<String> {
+        let application = Self::get_application(env.clone(), application_id)?;
+        let job = Self::get_job(env, application.job_id)?;
+        job.stages.get(application.stage?)
+    }
+    
+    // Helper functions
+    pub(crate) fn validate_stages(stages: &Vec<String>) -> Result<(), Error> {
+        if stages.len() > MAX_STAGES {
+            return Err(Error::InvalidStage);
+        }
+        for (index, stage) in stages.iter().enumerate() {
+            if stage.is_empty() || stages.first_index_of(&stage) != Some(index as u32) {
+                return Err(Error::InvalidStage);
+            }
+        }
+        Ok(())
+    }
+    
+    // Jobs with a custom pipeline only accept from its last stage
+    pub(crate) fn require_final_stage(env: &Env, application: &Application) -> Result<(), Error> {
+        let stage_count = Self::get_job(env.clone(), application.job_id)
+            .map(|job| job.stages.len())
+            .unwrap_or(0);
+        match application.stage {
+            Some(stage) if stage + 1 < stage_count => Err(Error::PipelineIncomplete),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Compensation, Currency, PayPeriod};
+    use soroban_sdk::{testutils::Address as _, vec};
+    
+    #[test]
+    fn test_custom_pipeline_stages() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, JobApplicationContract);
+        let client = JobApplicationContractClient::new(&env, &contract_id);
+        
+        let employer = Address::generate(&env);
+        let candidate = Address::generate(&env);
+        let cover = String::from_str(&env, "QmCover");
+        let title = String::from_str(&env, "Engineer");
+        let details = String::from_str(&env, "QmJob");
+        let compensation = Compensation {
+            min: 80_000,
+            max: 80_000,
+            currency: Currency::Iso(String::from_str(&env, "USD")),
+            period: PayPeriod::Yearly,
+            equity: false,
+        };
+        let screen = String::from_str(&env, "Phone screen");
+        let stages = vec![
+            &env,
+            screen.clone(),
+            String::from_str(&env, "Take-home"),
+            String::from_str(&env, "Onsite"),
+            String::from_str(&env, "Reference check"),
+        ];
+        
+        env.mock_all_auths();
+        
+        // Stage names must be non-empty and distinct
+        let duplicated = vec![&env, screen.clone(), screen.clone()];
+        assert_eq!(
+            client.try_post_job(&employer, &title, &details, &compensation, &None, &None, &duplicated).err(),
+            Some(Ok(Error::InvalidStage))
+        );
+        let job = client.post_job(&employer, &title, &details, &compensation, &None, &None, &stages);
+        assert_eq!(job.stages.len(), 4);
+        
+        let application = client.apply_to_job(&candidate, &1, &cover, &None);
+        assert_eq!(application.stage, Some(0));
+        assert_eq!(client.get_application_stage(&1), Some(screen.clone()));
+        
+        // Forward one step at a time; the first move takes the application into review
+        assert_eq!(client.try_move_to_stage(&employer, &1, &2).err(), Some(Ok(Error::InvalidStage)));
+        assert_eq!(client.try_move_to_stage(&employer, &1, &0).err(), Some(Ok(Error::InvalidStage)));
+        assert_eq!(client.try_move_to_stage(&candidate, &1, &1).err(), Some(Ok(Error::NotAuthorized)));
+        let moved = client.move_to_stage(&employer, &1, &1);
+        assert_eq!(moved.status, ApplicationStatus::UnderReview);
+        client.move_to_stage(&employer, &1, &2);
+        assert_eq!(client.try_accept_application(&employer, &1).err(), Some(Ok(Error::PipelineIncomplete)));
+        
+        // Back to any earlier stage, then through to the end
+        client.move_to_stage(&employer, &1, &0);
+        for stage in 1..4u32 {
+            client.move_to_stage(&employer, &1, &stage);
+        }
+        assert_eq!(client.try_move_to_stage(&employer, &1, &4).err(), Some(Ok(Error::InvalidStage)));
+        assert_eq!(client.get_application_stage(&1), Some(String::from_str(&env, "Reference check")));
+        assert_eq!(client.accept_application(&employer, &1).status, ApplicationStatus::Accepted);
+        assert_eq!(client.try_move_to_stage(&employer, &1, &2).err(), Some(Ok(Error::InvalidTransition)));
+        
+        // Every stage move is in the audit trail, named in the note
+        let history = client.get_application_history(&1);
+        assert_eq!(history.len(), 8);
+        assert_eq!(history.get(1).unwrap().note, Some(String::from_str(&env, "Take-home")));
+        assert_eq!(history.get(3).unwrap().note, Some(screen));
+        
+        // Jobs without stages keep the built-in flow
+        client.post_job(&employer, &title, &details, &compensation, &None, &None, &Vec::new(&env));
+        let plain = client.apply_to_job(&candidate, &2, &cover, &None);
+        assert_eq!(plain.stage, None);
+        assert_eq!(client.try_move_to_stage(&employer, &2, &0).err(), Some(Ok(Error::InvalidStage)));
+        client.review_application(&employer, &2);
+        client.accept_application(&employer, &2);
+    }
+}
@@ -0,0 +1,265 @@
+#![no_std]
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, String, Symbol, symbol_short};
+
+// Event topics (listed in the README)
+const VERIFIER: Symbol = symbol_short!("verifier");    // Registry entries
+const CONFIG: Symbol = symbol_short!("config");        // Admin wiring
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    VerifierAlreadyRegistered = 3,
+    VerifierNotFound = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifierCategory {
+    University,
+    Employer,
+    BackgroundCheckAgency,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifierStatus {
+    Active,
+    Suspended,
+}
+
+// Trusted verifier registered by the admin
+#[contracttype]
+#[derive(Clone)]
+pub struct Verifier {
+    pub address: Address,
+    pub category: VerifierCategory,
+    pub organization: String,       // Legal organization name
+    pub domain: String,             // e.g. "mit.edu"
+    pub ipfs_profile: String,       // Verifier profile/credentials on IPFS
+    pub status: VerifierStatus,
+    pub added_at: u64,
+    pub updated_at: u64,
+}
+
+#[contract]
+pub struct VerifierRegistryContract;
+
+#[contractimpl]
+impl VerifierRegistryContract {
+    
+    /// One-time setup of the registry admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().persistent().has(&symbol_short!("admin")) {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().persistent().set(&symbol_short!("admin"), &admin);
+        env.events().publish((CONFIG, symbol_short!("init")), admin);
+        Ok(())
+    }
+    
+    /// Hand the registry over to a new admin
+    pub fn set_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        new_admin.require_auth();
+        env.storage().persistent().set(&symbol_short!("admin"), &new_admin);
+        env.events().publish((CONFIG, symbol_short!("admin")), new_admin);
+        Ok(())
+    }
+    
+    /// Admin adds a trusted verifier
+    pub fn add_verifier(
+        env: Env,
+        verifier: Address,
+        category: VerifierCategory,
+        organization: String,
+        domain: String,
+        ipfs_profile: String,
+    ) -> Result<Verifier, Error> {
+        Self::require_admin(&env)?;
+        
+        let key = (symbol_short!("verifier"), verifier.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::VerifierAlreadyRegistered);
+        }
+        
+        let timestamp = env.ledger().timestamp();
+        let record = Verifier {
+            address: verifier,
+            category,
+            organization,
+            domain,
+            ipfs_profile,
+            status: VerifierStatus::Active,
+            added_at: timestamp,
+            updated_at: timestamp,
+        };
+        
+        env.storage().persistent().set(&key, &record);
+        env.events().publish((VERIFIER, symbol_short!("add"), record.address.clone()), record.clone());
+        Ok(record)
+    }
+    
+    /// Admin updates a verifier's metadata
+    pub fn update_verifier(
+        env: Env,
+        verifier: Address,
+        category: VerifierCategory,
+        organization: String,
+        domain: String,
+        ipfs_profile: String,
+    ) -> Result<Verifier, Error> {
+        Self::require_admin(&env)?;
+        
+        let key = (symbol_short!("verifier"), verifier);
+        let mut record: Verifier = env.storage().persistent()
+            .get(&key)
+            .ok_or(Error::VerifierNotFound)?;
+        
+        record.category = category;
+        record.organization = organization;
+        record.domain = domain;
+        record.ipfs_profile = ipfs_profile;
+        record.updated_at = env.ledger().timestamp();
+        
+        env.storage().persistent().set(&key, &record);
+        env.events().publish((VERIFIER, symbol_short!("update"), record.address.clone()), record.clone());
+        Ok(record)
+    }
+    
+    /// Admin temporarily blocks a verifier
+    pub fn suspend_verifier(env: Env, verifier: Address) -> Result<Verifier, Error> {
+        let record = Self::set_status(&env, verifier, VerifierStatus::Suspended)?;
+        env.events().publish((VERIFIER, symbol_short!("suspend"), record.address.clone()), ());
+        Ok(record)
+    }
+    
+    /// Admin lifts a suspension
+    pub fn reinstate_verifier(env: Env, verifier: Address) -> Result<Verifier, Error> {
+        let record = Self::set_status(&env, verifier, VerifierStatus::Active)?;
+        env.events().publish((VERIFIER, symbol_short!("reinstate"), record.address.clone()), ());
+        Ok(record)
+    }
+    
+    /// Admin removes a verifier from the registry
+    pub fn remove_verifier(env: Env, verifier: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        
+        let key = (symbol_short!("verifier"), verifier.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::VerifierNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        env.events().publish((VERIFIER, symbol_short!("remove"), verifier), ());
+        Ok(())
+    }
+    
+    /// Get verifier details
+    pub fn get_verifier(env: Env, verifier: Address) -> Option<Verifier> {
+        env.storage().persistent().get(&(symbol_short!("verifier"), verifier))
+    }
+    
+    /// Check if an address is registered and not suspended
+    pub fn is_active_verifier(env: Env, verifier: Address) -> bool {
+        match Self::get_verifier(env, verifier) {
+            Some(record) => record.status == VerifierStatus::Active,
+            None => false,
+        }
+    }
+    
+    /// Get registry admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&symbol_short!("admin"))
+    }
+    
+    // Helper functions
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env.storage().persistent()
+            .get(&symbol_short!("admin"))
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+    
+    fn set_status(env: &Env, verifier: Address, status: VerifierStatus) -> Result<Verifier, Error> {
+        Self::require_admin(env)?;
+        
+        let key = (symbol_short!("verifier"), verifier);
+        let mut record: Verifier = env.storage().persistent()
+            .get(&key)
+            .ok_or(Error::VerifierNotFound)?;
+        
+        record.status = status;
+        record.updated_at = env.ledger().timestamp();
+        
+        env.storage().persistent().set(&key, &record);
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_verifier_lifecycle() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, VerifierRegistryContract);
+        let client = VerifierRegistryContractClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        
+        env.mock_all_auths();
+        
+        client.initialize(&admin);
+        assert!(!client.is_active_verifier(&verifier));
+        
+        let record = client.add_verifier(
+            &verifier,
+            &VerifierCategory::University,
+            &String::from_str(&env, "MIT"),
+            &String::from_str(&env, "mit.edu"),
+            &String::from_str(&env, "QmVerifier"),
+        );
+        assert_eq!(record.status, VerifierStatus::Active);
+        assert!(client.is_active_verifier(&verifier));
+        
+        client.suspend_verifier(&verifier);
+        assert!(!client.is_active_verifier(&verifier));
+        
+        client.reinstate_verifier(&verifier);
+        assert!(client.is_active_verifier(&verifier));
+        
+        client.remove_verifier(&verifier);
+        assert!(client.get_verifier(&verifier).is_none());
+        assert_eq!(client.try_suspend_verifier(&verifier).err(), Some(Ok(Error::VerifierNotFound)));
+        assert_eq!(client.try_initialize(&admin).err(), Some(Ok(Error::AlreadyInitialized)));
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_add_verifier_requires_admin() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, VerifierRegistryContract);
+        let client = VerifierRegistryContractClient::new(&env, &contract_id);
+        
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize(&admin);
+        
+        // Drop the blanket auth mock so the admin signature is missing
+        env.set_auths(&[]);
+        client.add_verifier(
+            &Address::generate(&env),
+            &VerifierCategory::Employer,
+            &String::from_str(&env, "Acme"),
+            &String::from_str(&env, "acme.com"),
+            &String::from_str(&env, "QmAcme"),
+        );
+    }
+}
@@ -152,7 +152,10 @@ export default function RecruiterDashboard() {
         publicKey,
         jobTitle,
         ipfsCid,
-        "Competitive", // salary range
+        { min: BigInt(0), max: BigInt(0), currency: { iso: "USD" }, period: "Yearly", equity: false }, // undisclosed pay band
+        null, // no deadline
+        null, // no applicant cap
+        [], // built-in statuses only
         signTransaction
       )
       setTxHash(hash)
@@ -30,6 +30,9 @@ impl Contract {
 
     // Interview scheduling (simple): invite stores a metadata hash and timestamp
     // We use a composite key ("invite", candidate, recruiter, ts)
+    // The full flow, keyed by application ID, is in blockhire-contracts/job-application:
+    // invite -> propose_interview, accept_invite -> confirm_interview_slot,
+    // record_outcome -> record_interview_outcome, get_outcome -> get_application_interviews
     pub fn invite(_env: Env, _recruiter: Address, _candidate: Address, _ts: i64, _meta: Bytes) {
         // TODO: store invite
     }
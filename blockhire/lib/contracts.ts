@@ -121,6 +121,34 @@ export async function addDocument(
   return result.hash
 }
 
+export type PayPeriod = 'Hourly' | 'Monthly' | 'Yearly'
+
+// Typed pay band matching the contract's Compensation; amounts are in the currency's smallest unit
+export interface Compensation {
+  min: bigint
+  max: bigint
+  currency: { asset: string } | { iso: string }
+  period: PayPeriod
+  equity: boolean
+}
+
+function compensationToScVal(compensation: Compensation): StellarSdk.xdr.ScVal {
+  const { xdr } = StellarSdk
+  const currency = 'asset' in compensation.currency
+    ? xdr.ScVal.scvVec([xdr.ScVal.scvSymbol('Asset'), StellarSdk.Address.fromString(compensation.currency.asset).toScVal()])
+    : xdr.ScVal.scvVec([xdr.ScVal.scvSymbol('Iso'), StellarSdk.nativeToScVal(compensation.currency.iso, { type: 'string' })])
+  
+  // Struct fields are encoded as a map with keys in sorted order
+  const entry = (key: string, val: StellarSdk.xdr.ScVal) => new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(key), val })
+  return xdr.ScVal.scvMap([
+    entry('currency', currency),
+    entry('equity', xdr.ScVal.scvBool(compensation.equity)),
+    entry('max', StellarSdk.nativeToScVal(compensation.max, { type: 'i128' })),
+    entry('min', StellarSdk.nativeToScVal(compensation.min, { type: 'i128' })),
+    entry('period', xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(compensation.period)])),
+  ])
+}
+
 /**
  * Post a job (employer)
  */
@@ -128,7 +156,10 @@ export async function postJob(
   employerPublicKey: string,
   title: string,
   ipfsDetails: string,
-  salaryRange: string,
+  compensation: Compensation,
+  deadline: number | null,
+  maxApplications: number | null,
+  stages: string[],
   signTransaction: (xdr: string) => Promise<string>
 ): Promise<string> {
   const sourceAccount = await server.loadAccount(employerPublicKey)
@@ -144,7 +175,10 @@ export async function postJob(
         StellarSdk.Address.fromString(employerPublicKey).toScVal(),
         StellarSdk.nativeToScVal(title, { type: 'string' }),
         StellarSdk.nativeToScVal(ipfsDetails, { type: 'string' }),
-        StellarSdk.nativeToScVal(salaryRange, { type: 'string' })
+        compensationToScVal(compensation),
+        deadline === null ? StellarSdk.xdr.ScVal.scvVoid() : StellarSdk.nativeToScVal(deadline, { type: 'u64' }),
+        maxApplications === null ? StellarSdk.xdr.ScVal.scvVoid() : StellarSdk.nativeToScVal(maxApplications, { type: 'u32' }),
+        StellarSdk.xdr.ScVal.scvVec(stages.map((stage) => StellarSdk.nativeToScVal(stage, { type: 'string' })))
       )
     )
     .setTimeout(30)
@@ -163,8 +197,8 @@ export async function postJob(
 export async function applyToJob(
   candidatePublicKey: string,
   jobId: number,
-  profileCid: string,
   coverLetterCid: string,
+  referrer: string | null,
   signTransaction: (xdr: string) => Promise<string>
 ): Promise<string> {
   const sourceAccount = await server.loadAccount(candidatePublicKey)
@@ -179,8 +213,8 @@ export async function applyToJob(
         'apply_to_job',
         StellarSdk.Address.fromString(candidatePublicKey).toScVal(),
         StellarSdk.nativeToScVal(jobId, { type: 'u64' }),
-        StellarSdk.nativeToScVal(profileCid, { type: 'string' }),
-        StellarSdk.nativeToScVal(coverLetterCid, { type: 'string' })
+        StellarSdk.nativeToScVal(coverLetterCid, { type: 'string' }),
+        referrer === null ? StellarSdk.xdr.ScVal.scvVoid() : StellarSdk.Address.fromString(referrer).toScVal()
       )
     )
     .setTimeout(30)